@@ -1,8 +1,21 @@
-use arcade_jump_macros::jump_parameters;
+use arcade_jump_macros::{all_jump_parameters, jump_parameters};
+
+// Values evaluated at compile-time can be declared as items of the module
+jump_parameters![
+    use const f32;
+    20.0: Height, 10.0: Time => MODULE_IMPULSE: Impulse, MODULE_GRAVITY: Gravity;
+];
+
+// and used in other constant expressions
+const _: () = assert!(MODULE_IMPULSE == 4.0);
+const _: () = assert!(MODULE_GRAVITY == -0.4);
 
 fn main() {
     runtime_evaluate();
     compile_time_evaluate();
+    all_parameters_evaluate();
+    horizontal_evaluate();
+    struct_assign();
 }
 
 /// Evaluate values at runtime
@@ -38,3 +51,54 @@ fn compile_time_evaluate() {
     assert_eq!(LOWER_GRAVITY, -0.8);
     assert_eq!(HIGHER_GRAVITY, -1.6);
 }
+
+/// Evaluate all the parameters from two of them
+fn all_parameters_evaluate() {
+    let my_height: f32 = 20.0;
+    let my_time: f32 = 10.0;
+
+    let [height, time, impulse, gravity] =
+        all_jump_parameters![use f32; my_height: Height, my_time: Time];
+    assert_eq!([height, time, impulse, gravity], [20.0, 10.0, 4.0, -0.4]);
+
+    const PARAMETERS: [f64; 4] = all_jump_parameters![use const f64; 4.0: I, (-0.4): G];
+    assert_eq!(PARAMETERS, [20.0, 10.0, 4.0, -0.4]);
+}
+
+/// Evaluate the vertical parameters from the horizontal motion
+fn horizontal_evaluate() {
+    let my_speed: f32 = 4.0;
+    let my_range: f32 = 80.0;
+
+    jump_parameters![
+        use f32;
+        my_range: Range, my_speed: Speed => my_time: Time;
+        20.0: Height, my_time: Time => my_impulse: Impulse, my_gravity: Gravity;
+    ];
+    assert_eq!(my_time, 10.0);
+    assert_eq!(my_impulse, 4.0);
+    assert_eq!(my_gravity, -0.4);
+}
+
+/// Jump settings of a character
+#[derive(Default)]
+struct Character {
+    impulse: f32,
+    gravity: f32,
+}
+
+/// Assign the computed values to the fields of a struct
+fn struct_assign() {
+    let mut character = Character::default();
+
+    // the outputs are only stored in the fields, they can be read back from the struct
+    jump_parameters![
+        use f32;
+        into character;
+        20.0: Height, 10.0: Time => impulse: Impulse, gravity: Gravity;
+        (character.impulse): Impulse, (character.gravity): Gravity => time: Time;
+    ];
+    assert_eq!(character.impulse, 4.0);
+    assert_eq!(character.gravity, -0.4);
+    assert_eq!(time, 10.0);
+}
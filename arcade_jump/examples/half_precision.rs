@@ -0,0 +1,14 @@
+use arcade_jump::jump_parameter::{float16::f16, jump_parameters};
+
+fn main() {
+    let my_height = f16::from_f32(20.0);
+
+    jump_parameters![
+        use f16;
+        my_height: Height, 10.0: Time => my_impulse: Impulse, my_gravity: Gravity;
+        my_height: H, (my_impulse * f16::from_f32(2.0)): I => higher_grav: G;
+    ];
+    assert_eq!(my_impulse, f16::from_f32(4.0));
+    assert_eq!(my_gravity, f16::from_f32(-0.4));
+    assert_eq!(higher_grav, f16::from_f32(-1.6));
+}
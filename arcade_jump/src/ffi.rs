@@ -0,0 +1,410 @@
+/// Generate the C functions wrapping the jump parameter functions of a float module
+///
+/// Each function is exported as `arcade_jump_<function>_<float>`,
+/// the tuples and options returned are converted to `#[repr(C)]` structs.
+/// The arguments are floats unless a type is given, the arguments of the functions taking
+/// tuples or arrays are flattened and forwarded by the call given after a `=`.
+macro_rules! ffi_jump_parameter {
+    ($module:ident, $float:ident; $(
+        $func:ident($($arg:ident $(: $ty:ident)?),*) $(-> $ret:ident)? $(= ($($call:tt)*))?;
+    )*) => {
+        #[doc = concat!("C bindings of the `", stringify!($float), "` jump parameter functions")]
+        pub mod $module {
+            use super::IntoC;
+            use crate::jump_parameter::$module;
+
+            /// Two values returned together
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct Pair {
+                /// First value of the tuple
+                pub first: $float,
+
+                /// Second value of the tuple
+                pub second: $float,
+            }
+
+            /// Three values returned together
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct Triple {
+                /// First value of the tuple
+                pub first: $float,
+
+                /// Second value of the tuple
+                pub second: $float,
+
+                /// Third value of the tuple
+                pub third: $float,
+            }
+
+            /// A value which only exists if `valid` is set, it is NaN otherwise
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy)]
+            pub struct OptionValue {
+                /// Whether there is a value
+                pub valid: bool,
+
+                /// The value
+                pub value: $float,
+            }
+
+            /// Read the value if it is valid
+            impl OptionValue {
+                /// Get the value if it is valid
+                #[must_use]
+                pub fn into_option(self) -> Option<$float> {
+                    if self.valid {
+                        Some(self.value)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            /// Two values which only exist if `valid` is set, they are NaN otherwise
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy)]
+            pub struct OptionPair {
+                /// Whether there are values
+                pub valid: bool,
+
+                /// First value of the tuple
+                pub first: $float,
+
+                /// Second value of the tuple
+                pub second: $float,
+            }
+
+            /// Height, time, impulse and gravity which only exist if `valid` is set,
+            /// they are NaN otherwise
+            #[repr(C)]
+            #[derive(Debug, Clone, Copy)]
+            pub struct OptionQuad {
+                /// Whether there are values
+                pub valid: bool,
+
+                /// The values in the order height, time, impulse and gravity
+                pub values: [$float; 4],
+            }
+
+            /// The values of invalid results are not compared
+            impl PartialEq for OptionValue {
+                fn eq(&self, other: &Self) -> bool {
+                    self.valid == other.valid && (!self.valid || self.value == other.value)
+                }
+            }
+
+            /// The values of invalid results are not compared
+            impl PartialEq for OptionPair {
+                fn eq(&self, other: &Self) -> bool {
+                    self.valid == other.valid
+                        && (!self.valid || (self.first, self.second) == (other.first, other.second))
+                }
+            }
+
+            /// The values of invalid results are not compared
+            impl PartialEq for OptionQuad {
+                fn eq(&self, other: &Self) -> bool {
+                    self.valid == other.valid && (!self.valid || self.values == other.values)
+                }
+            }
+
+            impl IntoC<$float> for $float {
+                fn into_c(self) -> $float {
+                    self
+                }
+            }
+
+            impl IntoC<Pair> for ($float, $float) {
+                fn into_c(self) -> Pair {
+                    Pair { first: self.0, second: self.1 }
+                }
+            }
+
+            impl IntoC<Triple> for ($float, $float, $float) {
+                fn into_c(self) -> Triple {
+                    Triple { first: self.0, second: self.1, third: self.2 }
+                }
+            }
+
+            impl IntoC<OptionValue> for Option<$float> {
+                fn into_c(self) -> OptionValue {
+                    match self {
+                        Some(value) => OptionValue { valid: true, value },
+                        None => OptionValue { valid: false, value: $float::NAN },
+                    }
+                }
+            }
+
+            impl IntoC<OptionPair> for Option<($float, $float)> {
+                fn into_c(self) -> OptionPair {
+                    match self {
+                        Some((first, second)) => OptionPair { valid: true, first, second },
+                        None => OptionPair { valid: false, first: $float::NAN, second: $float::NAN },
+                    }
+                }
+            }
+
+            impl IntoC<OptionQuad> for Option<[$float; 4]> {
+                fn into_c(self) -> OptionQuad {
+                    match self {
+                        Some(values) => OptionQuad { valid: true, values },
+                        None => OptionQuad { valid: false, values: [$float::NAN; 4] },
+                    }
+                }
+            }
+
+            $(
+                #[doc = concat!(
+                    "Exported as `arcade_jump_", stringify!($func), "_", stringify!($float),
+                    "`, see [`", stringify!($module), "::", stringify!($func), "`]"
+                )]
+                #[must_use]
+                #[export_name = concat!("arcade_jump_", stringify!($func), "_", stringify!($float))]
+                pub extern "C" fn $func(
+                    $($arg: ffi_type!($float $(, $ty)?)),*
+                ) -> ffi_type!($float $(, $ret)?) {
+                    ffi_call!($module::$func; ($($arg),*) $(; ($($call)*))?).into_c()
+                }
+            )*
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::ffi::tests::Sample;
+
+                /// Only the height and the time are given to the partial solver
+                impl Sample for OptionValue {
+                    fn sample(name: &str) -> Self {
+                        Some($float::sample(name))
+                            .filter(|_| name == "height" || name == "time")
+                            .into_c()
+                    }
+                }
+
+                #[test]
+                fn test_ffi() {
+                    // every C function gives the same result as the function it wraps
+                    $({
+                        $(let $arg = Sample::sample(stringify!($arg));)*
+                        assert_eq!(
+                            super::$func($($arg),*),
+                            ffi_call!($module::$func; ($($arg),*) $(; ($($call)*))?).into_c(),
+                            stringify!($func)
+                        );
+                    })*
+                }
+            }
+        }
+    };
+}
+
+/// Type of an argument or of the result of a C function, the float type unless another is given
+macro_rules! ffi_type {
+    ($float:ident) => {
+        $float
+    };
+    ($float:ident, $ret:ident) => {
+        $ret
+    };
+}
+
+/// Call a jump parameter function with the arguments of the C function,
+/// or with the arguments given to forward them
+macro_rules! ffi_call {
+    ($path:path; ($($arg:ident),*)) => {
+        $path($($arg),*)
+    };
+    ($path:path; ($($arg:ident),*); ($($call:tt)*)) => {
+        $path($($call)*)
+    };
+}
+
+/// Conversion of a result to its C representation
+trait IntoC<T> {
+    /// Convert the result
+    fn into_c(self) -> T;
+}
+
+/// Generate the C bindings of every float module
+macro_rules! ffi_float_modules {
+    ($($module:ident, $float:ident;)*) => {
+        $(
+            ffi_jump_parameter!($module, $float;
+                height_from_time_and_impulse(time, impulse);
+                height_from_time_and_gravity(time, gravity);
+                height_from_impulse_and_gravity(impulse, gravity);
+                time_from_height_and_impulse(height, impulse);
+                time_from_height_and_gravity(height, gravity);
+                time_from_impulse_and_gravity(impulse, gravity);
+                impulse_from_height_and_time(height, time);
+                impulse_from_height_and_gravity(height, gravity);
+                signed_impulse_from_height_and_gravity(height, gravity);
+                impulse_from_time_and_gravity(time, gravity);
+                gravity_from_height_and_time(height, time);
+                gravity_from_height_and_impulse(height, impulse);
+                signed_gravity_from_height_and_impulse(height, impulse);
+                gravity_from_time_and_impulse(time, impulse);
+                time_from_speed_and_range(speed, range);
+                height_from_speed_range_and_gravity(speed, range, gravity);
+                gravity_from_height_speed_and_apex_distance(height, speed, apex_distance);
+                gravity_for_hang_time(height, hang_time, hang_threshold);
+                velocity_at_time(impulse, gravity, time);
+                kinetic_proxy(impulse, gravity, time);
+                total_energy(mass, impulse, gravity, time);
+                height_from_time_and_impulse_saturating(time, impulse);
+                height_from_time_and_gravity_saturating(time, gravity);
+                height_from_impulse_and_gravity_saturating(impulse, gravity);
+                time_from_height_and_impulse_saturating(height, impulse);
+                time_from_height_and_gravity_saturating(height, gravity);
+                time_from_impulse_and_gravity_saturating(impulse, gravity);
+                impulse_from_height_and_time_saturating(height, time);
+                impulse_from_height_and_gravity_saturating(height, gravity);
+                signed_impulse_from_height_and_gravity_saturating(height, gravity);
+                impulse_from_time_and_gravity_saturating(time, gravity);
+                gravity_from_height_and_time_saturating(height, time);
+                gravity_from_height_and_impulse_saturating(height, impulse);
+                signed_gravity_from_height_and_impulse_saturating(height, impulse);
+                gravity_from_time_and_impulse_saturating(time, impulse);
+                time_from_speed_and_range_saturating(speed, range);
+                height_from_speed_range_and_gravity_saturating(speed, range, gravity);
+                gravity_from_height_speed_and_apex_distance_saturating(height, speed, apex_distance);
+                gravity_for_hang_time_saturating(height, hang_time, hang_threshold);
+                velocity_at_time_saturating(impulse, gravity, time);
+                kinetic_proxy_saturating(impulse, gravity, time);
+                total_energy_saturating(mass, impulse, gravity, time);
+                time_from_speed_and_range_with_ratio(speed, range, ratio) -> Pair;
+                time_from_speed_and_range_with_ratio_saturating(speed, range, ratio) -> Pair;
+                trajectory_from_speed_range_and_height(speed, range, height) -> Triple;
+                trajectory_from_speed_range_and_height_saturating(speed, range, height) -> Triple;
+                ascent_and_fall_times(height, gravity_up, gravity_down) -> Pair;
+                ascent_and_fall_times_saturating(height, gravity_up, gravity_down) -> Pair;
+                times_at_height(impulse, gravity, height) -> OptionPair;
+                time_to_height_ascending(impulse, gravity, height) -> OptionValue;
+                launch_velocity_for_target(gravity, dx, dy, speed) -> OptionPair;
+                time_to_land_from_offset(impulse, gravity, launch_height, target_height) -> OptionValue;
+                solve_partial(
+                    height: OptionValue, time: OptionValue, impulse: OptionValue, gravity: OptionValue
+                ) -> OptionQuad = ([
+                    height.into_option(),
+                    time.into_option(),
+                    impulse.into_option(),
+                    gravity.into_option(),
+                ]);
+                solve_from_two_samples(time1, height1, time2, height2)
+                    -> OptionPair = ((time1, height1), (time2, height2));
+                solve_through_waypoint(
+                    launch_time, launch_height, waypoint_time, waypoint_height, total_time
+                ) -> OptionPair = ((launch_time, launch_height), (waypoint_time, waypoint_height), total_time);
+            );
+        )*
+    };
+}
+
+ffi_float_modules! {
+    float32, f32;
+    float64, f64;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jump_parameter::{float32, float64};
+
+    /// Value given to the arguments of the given name when testing every C function
+    pub(super) trait Sample {
+        /// Get the value of the argument
+        fn sample(name: &str) -> Self;
+    }
+
+    impl Sample for f32 {
+        fn sample(name: &str) -> Self {
+            match name {
+                "height" => 20.0,
+                "time" => 10.0,
+                "impulse" => 5.0,
+                "gravity" => -0.4,
+                "speed" => 2.0,
+                "range" => 40.0,
+                "ratio" => 0.25,
+                "apex_distance" => 10.0,
+                "hang_time" => 4.0,
+                "hang_threshold" => 1.0,
+                "mass" => 2.0,
+                "gravity_up" => -0.4,
+                "gravity_down" => -1.6,
+                "dx" => 4.0,
+                "dy" => 1.0,
+                "launch_height" => 0.0,
+                "target_height" => -5.0,
+                "time1" => 1.0,
+                "height1" => 4.8,
+                "time2" => 2.0,
+                "height2" => 9.2,
+                "launch_time" => 1.0,
+                "waypoint_time" => 5.0,
+                "waypoint_height" => 8.0,
+                "total_time" => 10.0,
+                _ => panic!("no sample value for `{name}`"),
+            }
+        }
+    }
+
+    impl Sample for f64 {
+        fn sample(name: &str) -> Self {
+            f32::sample(name).into()
+        }
+    }
+
+    #[test]
+    fn test_ffi() {
+        // the C functions give the same results, infinities included
+        let [height, time, impulse, gravity] = [20.0, 10.0, 4.0, -0.4];
+        assert_eq!(
+            super::float32::impulse_from_height_and_time(height, time),
+            float32::impulse_from_height_and_time(height, time)
+        );
+        assert_eq!(
+            super::float32::gravity_from_height_and_impulse(height, impulse),
+            float32::gravity_from_height_and_impulse(height, impulse)
+        );
+        assert_eq!(
+            super::float32::time_from_impulse_and_gravity(impulse, 0.0),
+            f32::INFINITY
+        );
+        assert_eq!(
+            super::float64::height_from_time_and_gravity(time.into(), gravity.into()),
+            float64::height_from_time_and_gravity(time.into(), gravity.into())
+        );
+        assert_eq!(
+            super::float64::gravity_from_height_and_time(height.into(), 0.0),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(
+            super::float64::gravity_from_height_and_time_saturating(height.into(), 0.0),
+            0.0
+        );
+
+        // the tuples and options are returned as structs
+        let times = super::float32::times_at_height(impulse, gravity, 0.0);
+        assert!(times.valid);
+        assert_eq!((times.first, times.second), (0.0, 2.0 * time));
+        assert!(!super::float64::times_at_height(impulse.into(), gravity.into(), 40.0).valid);
+        let given = |value| super::float32::OptionValue { valid: true, value };
+        let missing = super::float32::OptionValue {
+            valid: false,
+            value: f32::NAN,
+        };
+        let partial = super::float32::solve_partial(given(height), given(time), missing, missing);
+        assert!(partial.valid);
+        assert_eq!(partial.values, [height, time, impulse, gravity]);
+        let samples = super::float64::solve_from_two_samples(1.0, 3.8, 2.0, 7.2);
+        assert!(samples.valid);
+        assert!(!super::float64::solve_from_two_samples(1.0, 3.8, 1.0, 7.2).valid);
+        let trajectory = super::float32::trajectory_from_speed_range_and_height(2.0, 40.0, height);
+        assert_eq!(
+            (trajectory.first, trajectory.second, trajectory.third),
+            float32::trajectory_from_speed_range_and_height(2.0, 40.0, height)
+        );
+    }
+}
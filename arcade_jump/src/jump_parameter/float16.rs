@@ -0,0 +1,793 @@
+use super::float32;
+pub use half::f16;
+
+/// Widen a 16 bits floating point number to compute with 32 bits
+#[inline]
+const fn widen(value: f16) -> f32 {
+    value.to_f32_const()
+}
+
+/// Narrow the result of a computation back to 16 bits
+#[inline]
+const fn narrow(value: f32) -> f16 {
+    f16::from_f32_const(value)
+}
+
+/// Replace an infinite or undefined result by zero
+#[inline]
+const fn saturate(value: f16) -> f16 {
+    if value.is_finite() {
+        value
+    } else {
+        f16::ZERO
+    }
+}
+
+/// Compute the peak height from the time to reach the peak and the vertical impulse
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_impulse(time: f16, impulse: f16) -> f16 {
+    narrow(float32::height_from_time_and_impulse(
+        widen(time),
+        widen(impulse),
+    ))
+}
+
+/// Compute the peak height from the time to reach the peak and the gravity
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_gravity(time: f16, gravity: f16) -> f16 {
+    narrow(float32::height_from_time_and_gravity(
+        widen(time),
+        widen(gravity),
+    ))
+}
+
+/// Compute the peak height from the vertical impulse and the gravity
+#[inline]
+#[must_use]
+pub const fn height_from_impulse_and_gravity(impulse: f16, gravity: f16) -> f16 {
+    narrow(float32::height_from_impulse_and_gravity(
+        widen(impulse),
+        widen(gravity),
+    ))
+}
+
+/// Compute time to reach the peak from the peak height and the vertical impulse
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_impulse(height: f16, impulse: f16) -> f16 {
+    narrow(float32::time_from_height_and_impulse(
+        widen(height),
+        widen(impulse),
+    ))
+}
+
+/// Compute time to reach the peak from the peak height and the gravity
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_gravity(height: f16, gravity: f16) -> f16 {
+    narrow(float32::time_from_height_and_gravity(
+        widen(height),
+        widen(gravity),
+    ))
+}
+
+/// Compute time to reach the peak from the vertical impulse and the gravity
+#[inline]
+#[must_use]
+pub const fn time_from_impulse_and_gravity(impulse: f16, gravity: f16) -> f16 {
+    narrow(float32::time_from_impulse_and_gravity(
+        widen(impulse),
+        widen(gravity),
+    ))
+}
+
+/// Compute the vertical impulse from the peak height and the time to reach the peak
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_time(height: f16, time: f16) -> f16 {
+    narrow(float32::impulse_from_height_and_time(
+        widen(height),
+        widen(time),
+    ))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_gravity(height: f16, gravity: f16) -> f16 {
+    narrow(float32::impulse_from_height_and_gravity(
+        widen(height),
+        widen(gravity),
+    ))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity,
+/// directed against the gravity whatever the orientation of the vertical axis
+#[inline]
+#[must_use]
+pub const fn signed_impulse_from_height_and_gravity(height: f16, gravity: f16) -> f16 {
+    narrow(float32::signed_impulse_from_height_and_gravity(
+        widen(height),
+        widen(gravity),
+    ))
+}
+
+/// Compute the vertical impulse from the time to reach the peak and the gravity
+#[inline]
+#[must_use]
+pub const fn impulse_from_time_and_gravity(time: f16, gravity: f16) -> f16 {
+    narrow(float32::impulse_from_time_and_gravity(
+        widen(time),
+        widen(gravity),
+    ))
+}
+
+/// Compute the gravity from the peak height and the time to reach the peak
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_time(height: f16, time: f16) -> f16 {
+    narrow(float32::gravity_from_height_and_time(
+        widen(height),
+        widen(time),
+    ))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_impulse(height: f16, impulse: f16) -> f16 {
+    narrow(float32::gravity_from_height_and_impulse(
+        widen(height),
+        widen(impulse),
+    ))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse,
+/// directed against the impulse whatever the orientation of the vertical axis
+#[inline]
+#[must_use]
+pub const fn signed_gravity_from_height_and_impulse(height: f16, impulse: f16) -> f16 {
+    narrow(float32::signed_gravity_from_height_and_impulse(
+        widen(height),
+        widen(impulse),
+    ))
+}
+
+/// Compute the gravity from the time to reach the peak and the vertical impulse
+#[inline]
+#[must_use]
+pub const fn gravity_from_time_and_impulse(time: f16, impulse: f16) -> f16 {
+    narrow(float32::gravity_from_time_and_impulse(
+        widen(time),
+        widen(impulse),
+    ))
+}
+
+/// Compute the time to reach the peak from the horizontal speed and the range
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range(speed: f16, range: f16) -> f16 {
+    narrow(float32::time_from_speed_and_range(
+        widen(speed),
+        widen(range),
+    ))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the horizontal speed,
+/// the range and the ratio of the flight spent ascending
+///
+/// The two durations add up to the full flight time `range / speed`,
+/// a ratio of `0.5` gives `time_from_speed_and_range` for both of them.
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range_with_ratio(
+    speed: f16,
+    range: f16,
+    ratio: f16,
+) -> (f16, f16) {
+    let (ascend, descend) =
+        float32::time_from_speed_and_range_with_ratio(widen(speed), widen(range), widen(ratio));
+    (narrow(ascend), narrow(descend))
+}
+
+/// Compute the peak height from the horizontal speed, the range and the gravity
+#[inline]
+#[must_use]
+pub const fn height_from_speed_range_and_gravity(speed: f16, range: f16, gravity: f16) -> f16 {
+    narrow(float32::height_from_speed_range_and_gravity(
+        widen(speed),
+        widen(range),
+        widen(gravity),
+    ))
+}
+
+/// Compute the gravity from the peak height and the time spent within a threshold of the peak
+///
+/// The whole jump is considered when the threshold is greater than the peak height,
+/// no gravity can hold the jump within a threshold which is not positive.
+#[inline]
+#[must_use]
+pub const fn gravity_for_hang_time(height: f16, hang_time: f16, hang_threshold: f16) -> f16 {
+    narrow(float32::gravity_for_hang_time(
+        widen(height),
+        widen(hang_time),
+        widen(hang_threshold),
+    ))
+}
+
+/// Compute the gravity from the peak height, the horizontal speed
+/// and the horizontal distance to the peak
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_speed_and_apex_distance(
+    height: f16,
+    speed: f16,
+    apex_distance: f16,
+) -> f16 {
+    narrow(float32::gravity_from_height_speed_and_apex_distance(
+        widen(height),
+        widen(speed),
+        widen(apex_distance),
+    ))
+}
+
+/// Compute the vertical impulse, the gravity and the time to reach the peak
+/// from the horizontal speed, the range and the peak height
+#[inline]
+#[must_use]
+pub const fn trajectory_from_speed_range_and_height(
+    speed: f16,
+    range: f16,
+    height: f16,
+) -> (f16, f16, f16) {
+    let (impulse, gravity, time) =
+        float32::trajectory_from_speed_range_and_height(widen(speed), widen(range), widen(height));
+    (narrow(impulse), narrow(gravity), narrow(time))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the peak height,
+/// the gravity while ascending and the gravity while descending
+#[inline]
+#[must_use]
+pub const fn ascent_and_fall_times(height: f16, gravity_up: f16, gravity_down: f16) -> (f16, f16) {
+    (
+        time_from_height_and_gravity(height, gravity_up),
+        time_from_height_and_gravity(height, gravity_down),
+    )
+}
+
+/// Compute all the parameters `[height, time, impulse, gravity]` when exactly two of them are known
+///
+/// Returns `None` when fewer or more than two parameters are given.
+#[inline]
+#[must_use]
+pub const fn solve_partial(params: [Option<f16>; 4]) -> Option<[f16; 4]> {
+    match params {
+        [Some(h), Some(t), None, None] => Some([
+            h,
+            t,
+            impulse_from_height_and_time(h, t),
+            gravity_from_height_and_time(h, t),
+        ]),
+        [Some(h), None, Some(i), None] => Some([
+            h,
+            time_from_height_and_impulse(h, i),
+            i,
+            gravity_from_height_and_impulse(h, i),
+        ]),
+        [Some(h), None, None, Some(g)] => Some([
+            h,
+            time_from_height_and_gravity(h, g),
+            impulse_from_height_and_gravity(h, g),
+            g,
+        ]),
+        [None, Some(t), Some(i), None] => Some([
+            height_from_time_and_impulse(t, i),
+            t,
+            i,
+            gravity_from_time_and_impulse(t, i),
+        ]),
+        [None, Some(t), None, Some(g)] => Some([
+            height_from_time_and_gravity(t, g),
+            t,
+            impulse_from_time_and_gravity(t, g),
+            g,
+        ]),
+        [None, None, Some(i), Some(g)] => Some([
+            height_from_impulse_and_gravity(i, g),
+            time_from_impulse_and_gravity(i, g),
+            i,
+            g,
+        ]),
+        _ => None,
+    }
+}
+
+/// Compute the two times at which the jump goes through a height,
+/// on the way up and on the way down, from the vertical impulse and the gravity
+///
+/// The first time is negative when the height is below the launch,
+/// as that height is only crossed on the way up before the jump starts.
+/// Returns `None` when the height is above the peak or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn times_at_height(impulse: f16, gravity: f16, height: f16) -> Option<(f16, f16)> {
+    match float32::times_at_height(widen(impulse), widen(gravity), widen(height)) {
+        Some((ascending, descending)) => Some((narrow(ascending), narrow(descending))),
+        None => None,
+    }
+}
+
+/// Compute the time to reach a height on the way up from the vertical impulse and the gravity
+///
+/// Returns `None` when the height is above the peak, when it is only reached before the launch
+/// or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn time_to_height_ascending(impulse: f16, gravity: f16, height: f16) -> Option<f16> {
+    match float32::time_to_height_ascending(widen(impulse), widen(gravity), widen(height)) {
+        Some(time) => Some(narrow(time)),
+        None => None,
+    }
+}
+
+/// Compute the launch velocity `(horizontal, vertical)` reaching a target at the offset
+/// `(dx, dy)` from the gravity and the launch speed, following the lower of the two arcs
+///
+/// Returns `None` when the target is out of reach at this speed or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn launch_velocity_for_target(
+    gravity: f16,
+    dx: f16,
+    dy: f16,
+    speed: f16,
+) -> Option<(f16, f16)> {
+    match float32::launch_velocity_for_target(widen(gravity), widen(dx), widen(dy), widen(speed)) {
+        Some((horizontal, vertical)) => Some((narrow(horizontal), narrow(vertical))),
+        None => None,
+    }
+}
+
+/// Compute the time to land on a target height on the way down from the vertical impulse,
+/// the gravity and the height the jump starts from
+///
+/// Returns `None` when the target is above the peak or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn time_to_land_from_offset(
+    impulse: f16,
+    gravity: f16,
+    launch_height: f16,
+    target_height: f16,
+) -> Option<f16> {
+    match float32::time_to_land_from_offset(
+        widen(impulse),
+        widen(gravity),
+        widen(launch_height),
+        widen(target_height),
+    ) {
+        Some(time) => Some(narrow(time)),
+        None => None,
+    }
+}
+
+/// Compute the vertical velocity at a given time from the vertical impulse and the gravity
+#[inline]
+#[must_use]
+pub const fn velocity_at_time(impulse: f16, gravity: f16, time: f16) -> f16 {
+    narrow(float32::velocity_at_time(
+        widen(impulse),
+        widen(gravity),
+        widen(time),
+    ))
+}
+
+/// Compute the vertical kinetic energy per unit of mass at a given time
+/// from the vertical impulse and the gravity
+#[inline]
+#[must_use]
+pub const fn kinetic_proxy(impulse: f16, gravity: f16, time: f16) -> f16 {
+    narrow(float32::kinetic_proxy(
+        widen(impulse),
+        widen(gravity),
+        widen(time),
+    ))
+}
+
+/// Compute the vertical impulse and the gravity from two samples `(time, height)`
+/// of the same trajectory, the jump starting at time and height zero
+///
+/// Returns `None` when the samples are taken at the same time or at the start of the jump.
+#[inline]
+#[must_use]
+pub const fn solve_from_two_samples(
+    (time1, height1): (f16, f16),
+    (time2, height2): (f16, f16),
+) -> Option<(f16, f16)> {
+    match float32::solve_from_two_samples(
+        (widen(time1), widen(height1)),
+        (widen(time2), widen(height2)),
+    ) {
+        Some((impulse, gravity)) => Some((narrow(impulse), narrow(gravity))),
+        None => None,
+    }
+}
+
+/// Compute the vertical impulse and the gravity of a jump going through a waypoint,
+/// the points being given as `(time, height)` and the jump landing back at the launch height
+///
+/// Returns `None` when the waypoint is at the launch or at the landing time.
+#[inline]
+#[must_use]
+pub const fn solve_through_waypoint(
+    (launch_time, launch_height): (f16, f16),
+    (waypoint_time, waypoint_height): (f16, f16),
+    total_time: f16,
+) -> Option<(f16, f16)> {
+    match float32::solve_through_waypoint(
+        (widen(launch_time), widen(launch_height)),
+        (widen(waypoint_time), widen(waypoint_height)),
+        widen(total_time),
+    ) {
+        Some((impulse, gravity)) => Some((narrow(impulse), narrow(gravity))),
+        None => None,
+    }
+}
+
+/// Compute the vertical mechanical energy at a given time from the mass,
+/// the vertical impulse and the gravity
+///
+/// It stays constant along the jump as long as the gravity does,
+/// a jump switching gravity at the peak gains or loses energy there.
+#[inline]
+#[must_use]
+pub const fn total_energy(mass: f16, impulse: f16, gravity: f16, time: f16) -> f16 {
+    narrow(float32::total_energy(
+        widen(mass),
+        widen(impulse),
+        widen(gravity),
+        widen(time),
+    ))
+}
+
+/// Compute the peak height from the time to reach the peak and the vertical impulse,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_impulse_saturating(time: f16, impulse: f16) -> f16 {
+    saturate(height_from_time_and_impulse(time, impulse))
+}
+
+/// Compute the peak height from the time to reach the peak and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_gravity_saturating(time: f16, gravity: f16) -> f16 {
+    saturate(height_from_time_and_gravity(time, gravity))
+}
+
+/// Compute the peak height from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_impulse_and_gravity_saturating(impulse: f16, gravity: f16) -> f16 {
+    saturate(height_from_impulse_and_gravity(impulse, gravity))
+}
+
+/// Compute time to reach the peak from the peak height and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_impulse_saturating(height: f16, impulse: f16) -> f16 {
+    saturate(time_from_height_and_impulse(height, impulse))
+}
+
+/// Compute time to reach the peak from the peak height and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_gravity_saturating(height: f16, gravity: f16) -> f16 {
+    saturate(time_from_height_and_gravity(height, gravity))
+}
+
+/// Compute time to reach the peak from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_impulse_and_gravity_saturating(impulse: f16, gravity: f16) -> f16 {
+    saturate(time_from_impulse_and_gravity(impulse, gravity))
+}
+
+/// Compute the vertical impulse from the peak height and the time to reach the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_time_saturating(height: f16, time: f16) -> f16 {
+    saturate(impulse_from_height_and_time(height, time))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_gravity_saturating(height: f16, gravity: f16) -> f16 {
+    saturate(impulse_from_height_and_gravity(height, gravity))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity,
+/// directed against the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn signed_impulse_from_height_and_gravity_saturating(height: f16, gravity: f16) -> f16 {
+    saturate(signed_impulse_from_height_and_gravity(height, gravity))
+}
+
+/// Compute the vertical impulse from the time to reach the peak and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_time_and_gravity_saturating(time: f16, gravity: f16) -> f16 {
+    saturate(impulse_from_time_and_gravity(time, gravity))
+}
+
+/// Compute the gravity from the peak height and the time to reach the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_time_saturating(height: f16, time: f16) -> f16 {
+    saturate(gravity_from_height_and_time(height, time))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_impulse_saturating(height: f16, impulse: f16) -> f16 {
+    saturate(gravity_from_height_and_impulse(height, impulse))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse,
+/// directed against the impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn signed_gravity_from_height_and_impulse_saturating(height: f16, impulse: f16) -> f16 {
+    saturate(signed_gravity_from_height_and_impulse(height, impulse))
+}
+
+/// Compute the gravity from the time to reach the peak and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_time_and_impulse_saturating(time: f16, impulse: f16) -> f16 {
+    saturate(gravity_from_time_and_impulse(time, impulse))
+}
+
+/// Compute the time to reach the peak from the horizontal speed and the range, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range_saturating(speed: f16, range: f16) -> f16 {
+    saturate(time_from_speed_and_range(speed, range))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the horizontal speed,
+/// the range and the ratio of the flight spent ascending, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range_with_ratio_saturating(
+    speed: f16,
+    range: f16,
+    ratio: f16,
+) -> (f16, f16) {
+    let (ascend, descend) = time_from_speed_and_range_with_ratio(speed, range, ratio);
+    (saturate(ascend), saturate(descend))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the peak height,
+/// the gravity while ascending and the gravity while descending, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn ascent_and_fall_times_saturating(
+    height: f16,
+    gravity_up: f16,
+    gravity_down: f16,
+) -> (f16, f16) {
+    let (ascend, descend) = ascent_and_fall_times(height, gravity_up, gravity_down);
+    (saturate(ascend), saturate(descend))
+}
+
+/// Compute the vertical impulse, the gravity and the time to reach the peak
+/// from the horizontal speed, the range and the peak height, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn trajectory_from_speed_range_and_height_saturating(
+    speed: f16,
+    range: f16,
+    height: f16,
+) -> (f16, f16, f16) {
+    let (impulse, gravity, time) = trajectory_from_speed_range_and_height(speed, range, height);
+    (saturate(impulse), saturate(gravity), saturate(time))
+}
+
+/// Compute the peak height from the horizontal speed, the range and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_speed_range_and_gravity_saturating(
+    speed: f16,
+    range: f16,
+    gravity: f16,
+) -> f16 {
+    saturate(height_from_speed_range_and_gravity(speed, range, gravity))
+}
+
+/// Compute the gravity from the peak height, the horizontal speed
+/// and the horizontal distance to the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_speed_and_apex_distance_saturating(
+    height: f16,
+    speed: f16,
+    apex_distance: f16,
+) -> f16 {
+    saturate(gravity_from_height_speed_and_apex_distance(
+        height,
+        speed,
+        apex_distance,
+    ))
+}
+
+/// Compute the gravity from the peak height and the time spent within a threshold of the peak,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_for_hang_time_saturating(
+    height: f16,
+    hang_time: f16,
+    hang_threshold: f16,
+) -> f16 {
+    saturate(gravity_for_hang_time(height, hang_time, hang_threshold))
+}
+
+/// Compute the vertical velocity at a given time from the vertical impulse and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn velocity_at_time_saturating(impulse: f16, gravity: f16, time: f16) -> f16 {
+    saturate(velocity_at_time(impulse, gravity, time))
+}
+
+/// Compute the vertical kinetic energy per unit of mass at a given time
+/// from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn kinetic_proxy_saturating(impulse: f16, gravity: f16, time: f16) -> f16 {
+    saturate(kinetic_proxy(impulse, gravity, time))
+}
+
+/// Compute the vertical mechanical energy at a given time from the mass,
+/// the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn total_energy_saturating(mass: f16, impulse: f16, gravity: f16, time: f16) -> f16 {
+    saturate(total_energy(mass, impulse, gravity, time))
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Assert that a 16 bits result matches the 32 bits one within half precision
+    fn assert_close(half: super::f16, single: f32) {
+        const TOLERANCE: f32 = 1e-3;
+        let half = half.to_f32();
+        assert!(
+            (half - single).abs() <= TOLERANCE * single.abs(),
+            "{half} is too far from {single}"
+        );
+    }
+
+    #[test]
+    fn test_derived_functions() {
+        use super::*;
+
+        let half = f16::from_f32;
+        let [height, time, impulse, gravity] = [20.0, 10.0, 4.0, -0.5];
+        let [h, t, i, g] = [height, time, impulse, gravity].map(half);
+        let [speed, range] = [2.0, 40.0];
+        let [s, r] = [speed, range].map(half);
+
+        #[rustfmt::skip]
+        let results = [
+            (signed_impulse_from_height_and_gravity(h, g), float32::signed_impulse_from_height_and_gravity(height, gravity)),
+            (signed_gravity_from_height_and_impulse(h, i), float32::signed_gravity_from_height_and_impulse(height, impulse)),
+            (time_from_speed_and_range(s, r), float32::time_from_speed_and_range(speed, range)),
+            (height_from_speed_range_and_gravity(s, r, g), float32::height_from_speed_range_and_gravity(speed, range, gravity)),
+            (gravity_for_hang_time(h, half(4.0), half(1.0)), float32::gravity_for_hang_time(height, 4.0, 1.0)),
+            (gravity_from_height_speed_and_apex_distance(h, s, half(10.0)), float32::gravity_from_height_speed_and_apex_distance(height, speed, 10.0)),
+            (velocity_at_time(i, g, half(2.0)), float32::velocity_at_time(impulse, gravity, 2.0)),
+            (kinetic_proxy(i, g, half(2.0)), float32::kinetic_proxy(impulse, gravity, 2.0)),
+            (total_energy(half(2.0), i, g, half(2.0)), float32::total_energy(2.0, impulse, gravity, 2.0)),
+            (time_from_speed_and_range_with_ratio(s, r, half(0.25)).0, float32::time_from_speed_and_range_with_ratio(speed, range, 0.25).0),
+            (time_from_speed_and_range_with_ratio(s, r, half(0.25)).1, float32::time_from_speed_and_range_with_ratio(speed, range, 0.25).1),
+            (trajectory_from_speed_range_and_height(s, r, h).0, float32::trajectory_from_speed_range_and_height(speed, range, height).0),
+            (trajectory_from_speed_range_and_height(s, r, h).1, float32::trajectory_from_speed_range_and_height(speed, range, height).1),
+            (trajectory_from_speed_range_and_height(s, r, h).2, float32::trajectory_from_speed_range_and_height(speed, range, height).2),
+            (ascent_and_fall_times(h, g, half(-2.0)).0, float32::ascent_and_fall_times(height, gravity, -2.0).0),
+            (ascent_and_fall_times(h, g, half(-2.0)).1, float32::ascent_and_fall_times(height, gravity, -2.0).1),
+        ];
+        for (half, single) in results {
+            assert_close(half, single);
+        }
+
+        // the functions which may find no solution find one for the same inputs
+        let partial = solve_partial([Some(h), Some(t), None, None]).unwrap();
+        let expected = float32::solve_partial([Some(height), Some(time), None, None]).unwrap();
+        for (half, single) in partial.into_iter().zip(expected) {
+            assert_close(half, single);
+        }
+
+        #[rustfmt::skip]
+        let pairs = [
+            (times_at_height(i, g, half(8.0)), float32::times_at_height(impulse, gravity, 8.0)),
+            (launch_velocity_for_target(g, half(4.0), half(1.0), s), float32::launch_velocity_for_target(gravity, 4.0, 1.0, speed)),
+            (solve_from_two_samples((half(1.0), half(3.75)), (half(2.0), half(7.0))), float32::solve_from_two_samples((1.0, 3.75), (2.0, 7.0))),
+            (solve_through_waypoint((half(1.0), f16::ZERO), (half(5.0), half(8.0)), t), float32::solve_through_waypoint((1.0, 0.0), (5.0, 8.0), time)),
+        ];
+        for (half, single) in pairs {
+            let ((half1, half2), (single1, single2)) = (half.unwrap(), single.unwrap());
+            assert_close(half1, single1);
+            assert_close(half2, single2);
+        }
+
+        #[rustfmt::skip]
+        let options = [
+            (time_to_height_ascending(i, g, half(8.0)), float32::time_to_height_ascending(impulse, gravity, 8.0)),
+            (time_to_land_from_offset(i, g, f16::ZERO, half(-4.0)), float32::time_to_land_from_offset(impulse, gravity, 0.0, -4.0)),
+        ];
+        for (half, single) in options {
+            assert_close(half.unwrap(), single.unwrap());
+        }
+
+        // and none when there is no solution
+        assert_eq!(times_at_height(i, g, h), None);
+        assert_eq!(time_to_land_from_offset(i, g, f16::ZERO, h), None);
+    }
+
+    #[test]
+    fn test_jump_parameter() {
+        use super::*;
+
+        let [height, time, impulse, gravity] = [20.0, 10.0, 4.0, -0.5];
+        let [h, t, i, g] = [height, time, impulse, gravity].map(f16::from_f32);
+
+        #[rustfmt::skip]
+        let results = [
+            (height_from_time_and_impulse(t, i), float32::height_from_time_and_impulse(time, impulse)),
+            (height_from_time_and_gravity(t, g), float32::height_from_time_and_gravity(time, gravity)),
+            (height_from_impulse_and_gravity(i, g), float32::height_from_impulse_and_gravity(impulse, gravity)),
+            (time_from_height_and_impulse(h, i), float32::time_from_height_and_impulse(height, impulse)),
+            (time_from_height_and_gravity(h, g), float32::time_from_height_and_gravity(height, gravity)),
+            (time_from_impulse_and_gravity(i, g), float32::time_from_impulse_and_gravity(impulse, gravity)),
+            (impulse_from_height_and_time(h, t), float32::impulse_from_height_and_time(height, time)),
+            (impulse_from_height_and_gravity(h, g), float32::impulse_from_height_and_gravity(height, gravity)),
+            (impulse_from_time_and_gravity(t, g), float32::impulse_from_time_and_gravity(time, gravity)),
+            (gravity_from_height_and_time(h, t), float32::gravity_from_height_and_time(height, time)),
+            (gravity_from_height_and_impulse(h, i), float32::gravity_from_height_and_impulse(height, impulse)),
+            (gravity_from_time_and_impulse(t, i), float32::gravity_from_time_and_impulse(time, impulse)),
+        ];
+        for (half, single) in results {
+            assert_close(half, single);
+        }
+
+        // results out of the range of 16 bits numbers saturate to zero
+        const IMPULSE: f16 = impulse_from_height_and_time(f16::MAX, f16::from_f32_const(0.5));
+        assert_eq!(IMPULSE, f16::INFINITY);
+        assert_eq!(
+            impulse_from_height_and_time_saturating(f16::MAX, f16::from_f32_const(0.5)),
+            f16::ZERO
+        );
+        assert_eq!(
+            height_from_time_and_impulse_saturating(f16::MAX, f16::MAX),
+            f16::ZERO
+        );
+        assert_eq!(total_energy_saturating(f16::MAX, i, g, f16::MAX), f16::ZERO);
+        assert_eq!(
+            trajectory_from_speed_range_and_height_saturating(f16::ZERO, t, h),
+            (f16::ZERO, f16::ZERO, f16::ZERO)
+        );
+    }
+}
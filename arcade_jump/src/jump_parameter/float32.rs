@@ -3,18 +3,21 @@ use const_soft_float::soft_f32::SoftF32;
 
 /// Compute the peak height from the time to reach the peak and the vertical impulse
 #[inline]
+#[must_use]
 pub const fn height_from_time_and_impulse(time: f32, impulse: f32) -> f32 {
     0.5 * impulse * time
 }
 
 /// Compute the peak height from the time to reach the peak and the gravity
 #[inline]
+#[must_use]
 pub const fn height_from_time_and_gravity(time: f32, gravity: f32) -> f32 {
     -0.5 * gravity * pow2![time]
 }
 
 /// Compute the peak height from the vertical impulse and the gravity
 #[inline]
+#[must_use]
 pub const fn height_from_impulse_and_gravity(impulse: f32, gravity: f32) -> f32 {
     if gravity == 0.0 {
         f32::INFINITY
@@ -25,6 +28,7 @@ pub const fn height_from_impulse_and_gravity(impulse: f32, gravity: f32) -> f32
 
 /// Compute time to reach the peak from the peak height and the vertical impulse
 #[inline]
+#[must_use]
 pub const fn time_from_height_and_impulse(height: f32, impulse: f32) -> f32 {
     if impulse == 0.0 {
         f32::INFINITY
@@ -35,6 +39,7 @@ pub const fn time_from_height_and_impulse(height: f32, impulse: f32) -> f32 {
 
 /// Compute time to reach the peak from the peak height and the gravity
 #[inline]
+#[must_use]
 pub const fn time_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
     if gravity == 0.0 {
         f32::INFINITY
@@ -47,6 +52,7 @@ pub const fn time_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
 /*
 /// Compute time to reach the peak from the peak height and the gravity
 #[inline]
+#[must_use]
 pub fn time_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
     if gravity == 0.0 {
         f32::INFINITY
@@ -58,6 +64,7 @@ pub fn time_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
 
 /// Compute time to reach the peak from the vertical impulse and the gravity
 #[inline]
+#[must_use]
 pub const fn time_from_impulse_and_gravity(impulse: f32, gravity: f32) -> f32 {
     if gravity == 0.0 {
         f32::INFINITY
@@ -68,6 +75,7 @@ pub const fn time_from_impulse_and_gravity(impulse: f32, gravity: f32) -> f32 {
 
 /// Compute the vertical impulse from the peak height and the time to reach the peak
 #[inline]
+#[must_use]
 pub const fn impulse_from_height_and_time(height: f32, time: f32) -> f32 {
     if time == 0.0 {
         f32::INFINITY
@@ -78,6 +86,7 @@ pub const fn impulse_from_height_and_time(height: f32, time: f32) -> f32 {
 
 /// Compute the vertical impulse from the peak height and the gravity
 #[inline]
+#[must_use]
 pub const fn impulse_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
     let f = 2.0 * height * gravity;
     SoftF32(if f >= 0.0 { f } else { -f }).sqrt().to_f32()
@@ -86,19 +95,35 @@ pub const fn impulse_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
 /*
 /// Compute the vertical impulse from the peak height and the gravity
 #[inline]
+#[must_use]
 pub fn impulse_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
     (2.0 * height * gravity).abs().sqrt()
 }
 // */
 
+/// Compute the vertical impulse from the peak height and the gravity,
+/// directed against the gravity whatever the orientation of the vertical axis
+#[inline]
+#[must_use]
+pub const fn signed_impulse_from_height_and_gravity(height: f32, gravity: f32) -> f32 {
+    let impulse = impulse_from_height_and_gravity(height, gravity);
+    if gravity > 0.0 {
+        -impulse
+    } else {
+        impulse
+    }
+}
+
 /// Compute the vertical impulse from the time to reach the peak and the gravity
 #[inline]
+#[must_use]
 pub const fn impulse_from_time_and_gravity(time: f32, gravity: f32) -> f32 {
     -gravity * time
 }
 
 /// Compute the gravity from the peak height and the time to reach the peak
 #[inline]
+#[must_use]
 pub const fn gravity_from_height_and_time(height: f32, time: f32) -> f32 {
     if time == 0.0 {
         f32::NEG_INFINITY
@@ -109,6 +134,7 @@ pub const fn gravity_from_height_and_time(height: f32, time: f32) -> f32 {
 
 /// Compute the gravity from the peak height and the vertical impulse
 #[inline]
+#[must_use]
 pub const fn gravity_from_height_and_impulse(height: f32, impulse: f32) -> f32 {
     if height == 0.0 {
         f32::NEG_INFINITY
@@ -117,8 +143,23 @@ pub const fn gravity_from_height_and_impulse(height: f32, impulse: f32) -> f32 {
     }
 }
 
+/// Compute the gravity from the peak height and the vertical impulse,
+/// directed against the impulse whatever the orientation of the vertical axis
+#[inline]
+#[must_use]
+pub const fn signed_gravity_from_height_and_impulse(height: f32, impulse: f32) -> f32 {
+    let distance = if height >= 0.0 { height } else { -height };
+    let gravity = gravity_from_height_and_impulse(distance, impulse);
+    if impulse < 0.0 {
+        -gravity
+    } else {
+        gravity
+    }
+}
+
 /// Compute the gravity from the time to reach the peak and the vertical impulse
 #[inline]
+#[must_use]
 pub const fn gravity_from_time_and_impulse(time: f32, impulse: f32) -> f32 {
     if time == 0.0 {
         f32::NEG_INFINITY
@@ -129,6 +170,7 @@ pub const fn gravity_from_time_and_impulse(time: f32, impulse: f32) -> f32 {
 
 /// Compute the time to reach the peak from the horizontal speed and the range
 #[inline]
+#[must_use]
 pub const fn time_from_speed_and_range(speed: f32, range: f32) -> f32 {
     if speed == 0.0 {
         f32::INFINITY
@@ -137,8 +179,13 @@ pub const fn time_from_speed_and_range(speed: f32, range: f32) -> f32 {
     }
 }
 
-/// Compute the time to reach the peak from the horizontal speed, the range and an arbitrary ratio
+/// Compute the time to reach the peak and the time to fall back from the horizontal speed,
+/// the range and the ratio of the flight spent ascending
+///
+/// The two durations add up to the full flight time `range / speed`,
+/// a ratio of `0.5` gives `time_from_speed_and_range` for both of them.
 #[inline]
+#[must_use]
 pub const fn time_from_speed_and_range_with_ratio(
     speed: f32,
     range: f32,
@@ -152,6 +199,592 @@ pub const fn time_from_speed_and_range_with_ratio(
     }
 }
 
+/// Compute the peak height from the horizontal speed, the range and the gravity
+#[inline]
+#[must_use]
+pub const fn height_from_speed_range_and_gravity(speed: f32, range: f32, gravity: f32) -> f32 {
+    if speed == 0.0 {
+        f32::INFINITY
+    } else {
+        height_from_time_and_gravity(time_from_speed_and_range(speed, range), gravity)
+    }
+}
+
+/// Compute the gravity from the peak height and the time spent within a threshold of the peak
+///
+/// The whole jump is considered when the threshold is greater than the peak height,
+/// no gravity can hold the jump within a threshold which is not positive.
+#[inline]
+#[must_use]
+pub const fn gravity_for_hang_time(height: f32, hang_time: f32, hang_threshold: f32) -> f32 {
+    if hang_time == 0.0 || hang_threshold <= 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        // the jump is above `height - threshold` during `2 * sqrt(2 * threshold / -gravity)`
+        let threshold = if hang_threshold < height {
+            hang_threshold
+        } else {
+            height
+        };
+        -8.0 * threshold / pow2![hang_time]
+    }
+}
+
+/// Compute the gravity from the peak height, the horizontal speed
+/// and the horizontal distance to the peak
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_speed_and_apex_distance(
+    height: f32,
+    speed: f32,
+    apex_distance: f32,
+) -> f32 {
+    if speed == 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        gravity_from_height_and_time(height, apex_distance / speed)
+    }
+}
+
+/// Compute the vertical impulse, the gravity and the time to reach the peak
+/// from the horizontal speed, the range and the peak height
+#[inline]
+#[must_use]
+pub const fn trajectory_from_speed_range_and_height(
+    speed: f32,
+    range: f32,
+    height: f32,
+) -> (f32, f32, f32) {
+    if speed == 0.0 {
+        (f32::INFINITY, f32::NEG_INFINITY, f32::INFINITY)
+    } else {
+        let time = time_from_speed_and_range(speed, range);
+        (
+            impulse_from_height_and_time(height, time),
+            gravity_from_height_and_time(height, time),
+            time,
+        )
+    }
+}
+
+/// Compute the time to reach the peak and the time to fall back from the peak height,
+/// the gravity while ascending and the gravity while descending
+#[inline]
+#[must_use]
+pub const fn ascent_and_fall_times(height: f32, gravity_up: f32, gravity_down: f32) -> (f32, f32) {
+    (
+        time_from_height_and_gravity(height, gravity_up),
+        time_from_height_and_gravity(height, gravity_down),
+    )
+}
+
+/// Compute all the parameters `[height, time, impulse, gravity]` when exactly two of them are known
+///
+/// Returns `None` when fewer or more than two parameters are given.
+#[inline]
+#[must_use]
+pub const fn solve_partial(params: [Option<f32>; 4]) -> Option<[f32; 4]> {
+    match params {
+        [Some(h), Some(t), None, None] => Some([
+            h,
+            t,
+            impulse_from_height_and_time(h, t),
+            gravity_from_height_and_time(h, t),
+        ]),
+        [Some(h), None, Some(i), None] => Some([
+            h,
+            time_from_height_and_impulse(h, i),
+            i,
+            gravity_from_height_and_impulse(h, i),
+        ]),
+        [Some(h), None, None, Some(g)] => Some([
+            h,
+            time_from_height_and_gravity(h, g),
+            impulse_from_height_and_gravity(h, g),
+            g,
+        ]),
+        [None, Some(t), Some(i), None] => Some([
+            height_from_time_and_impulse(t, i),
+            t,
+            i,
+            gravity_from_time_and_impulse(t, i),
+        ]),
+        [None, Some(t), None, Some(g)] => Some([
+            height_from_time_and_gravity(t, g),
+            t,
+            impulse_from_time_and_gravity(t, g),
+            g,
+        ]),
+        [None, None, Some(i), Some(g)] => Some([
+            height_from_impulse_and_gravity(i, g),
+            time_from_impulse_and_gravity(i, g),
+            i,
+            g,
+        ]),
+        _ => None,
+    }
+}
+
+/// Compute the two times at which the jump goes through a height,
+/// on the way up and on the way down, from the vertical impulse and the gravity
+///
+/// The first time is negative when the height is below the launch,
+/// as that height is only crossed on the way up before the jump starts.
+/// Returns `None` when the height is above the peak or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn times_at_height(impulse: f32, gravity: f32, height: f32) -> Option<(f32, f32)> {
+    // solve `height = impulse * time + 0.5 * gravity * time^2`
+    let discriminant = pow2![impulse] + 2.0 * gravity * height;
+    if gravity == 0.0 || discriminant < 0.0 {
+        None
+    } else {
+        let root = SoftF32(discriminant).sqrt().to_f32();
+        let time1 = (-impulse + root) / gravity;
+        let time2 = (-impulse - root) / gravity;
+        if time1 <= time2 {
+            Some((time1, time2))
+        } else {
+            Some((time2, time1))
+        }
+    }
+}
+
+/// Compute the time to reach a height on the way up from the vertical impulse and the gravity
+///
+/// Returns `None` when the height is above the peak, when it is only reached before the launch
+/// or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn time_to_height_ascending(impulse: f32, gravity: f32, height: f32) -> Option<f32> {
+    match times_at_height(impulse, gravity, height) {
+        Some((ascending, _)) if ascending >= 0.0 => Some(ascending),
+        _ => None,
+    }
+}
+
+/// Compute the launch velocity `(horizontal, vertical)` reaching a target at the offset
+/// `(dx, dy)` from the gravity and the launch speed, following the lower of the two arcs
+///
+/// Returns `None` when the target is out of reach at this speed or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn launch_velocity_for_target(
+    gravity: f32,
+    dx: f32,
+    dy: f32,
+    speed: f32,
+) -> Option<(f32, f32)> {
+    let speed2 = pow2![speed];
+    let discriminant = pow2![speed2] + gravity * (2.0 * dy * speed2 - gravity * pow2![dx]);
+    if gravity == 0.0 || discriminant < 0.0 {
+        None
+    } else if dx == 0.0 {
+        // straight up or straight down
+        Some((0.0, if dy >= 0.0 { speed } else { -speed }))
+    } else {
+        // tangent of the launch angle, measured toward the target
+        let distance = if dx >= 0.0 { dx } else { -dx };
+        let root = SoftF32(discriminant).sqrt().to_f32();
+        let tangent = (speed2 - root) / (-gravity * distance);
+        let cosine = 1.0 / SoftF32(1.0 + pow2![tangent]).sqrt().to_f32();
+        let horizontal = speed * cosine;
+        Some((
+            if dx >= 0.0 { horizontal } else { -horizontal },
+            horizontal * tangent,
+        ))
+    }
+}
+
+/// Compute the time to land on a target height on the way down from the vertical impulse,
+/// the gravity and the height the jump starts from
+///
+/// Returns `None` when the target is above the peak or when there is no gravity.
+#[inline]
+#[must_use]
+pub const fn time_to_land_from_offset(
+    impulse: f32,
+    gravity: f32,
+    launch_height: f32,
+    target_height: f32,
+) -> Option<f32> {
+    match times_at_height(impulse, gravity, target_height - launch_height) {
+        Some((_, descending)) => Some(descending),
+        None => None,
+    }
+}
+
+/// Compute the vertical velocity at a given time from the vertical impulse and the gravity
+#[inline]
+#[must_use]
+pub const fn velocity_at_time(impulse: f32, gravity: f32, time: f32) -> f32 {
+    impulse + gravity * time
+}
+
+/// Compute the vertical kinetic energy per unit of mass at a given time
+/// from the vertical impulse and the gravity
+///
+/// Evaluated at the end of the fall, it measures how hard the landing is.
+#[inline]
+#[must_use]
+pub const fn kinetic_proxy(impulse: f32, gravity: f32, time: f32) -> f32 {
+    let velocity = velocity_at_time(impulse, gravity, time);
+    0.5 * velocity * velocity
+}
+
+/// Compute the vertical mechanical energy at a given time from the mass,
+/// the vertical impulse and the gravity
+///
+/// It stays constant along the jump as long as the gravity does,
+/// a jump switching gravity at the peak gains or loses energy there.
+#[inline]
+#[must_use]
+pub const fn total_energy(mass: f32, impulse: f32, gravity: f32, time: f32) -> f32 {
+    // the potential energy grows against the gravity, whichever way the vertical axis points
+    let height = impulse * time + 0.5 * gravity * pow2![time];
+    mass * kinetic_proxy(impulse, gravity, time) - mass * gravity * height
+}
+
+/// Compute the vertical impulse and the gravity from two samples `(time, height)`
+/// of the same trajectory, the jump starting at time and height zero
+///
+/// Returns `None` when the samples are taken at the same time or at the start of the jump.
+#[inline]
+#[must_use]
+pub const fn solve_from_two_samples(
+    (time1, height1): (f32, f32),
+    (time2, height2): (f32, f32),
+) -> Option<(f32, f32)> {
+    // solve `height = impulse * time + 0.5 * gravity * time^2` for both samples
+    let det = 0.5 * time1 * time2 * (time2 - time1);
+    if det == 0.0 {
+        None
+    } else {
+        let impulse = 0.5 * (height1 * pow2![time2] - height2 * pow2![time1]) / det;
+        let gravity = (time1 * height2 - time2 * height1) / det;
+        Some((impulse, gravity))
+    }
+}
+
+/// Compute the vertical impulse and the gravity of a jump going through a waypoint,
+/// the points being given as `(time, height)` and the jump landing back at the launch height
+///
+/// Returns `None` when the waypoint is at the launch or at the landing time.
+#[inline]
+#[must_use]
+pub const fn solve_through_waypoint(
+    (launch_time, launch_height): (f32, f32),
+    (waypoint_time, waypoint_height): (f32, f32),
+    total_time: f32,
+) -> Option<(f32, f32)> {
+    // the landing gives `impulse = -0.5 * gravity * total_time`,
+    // then the waypoint gives `height = 0.5 * gravity * time * (time - total_time)`
+    let time = waypoint_time - launch_time;
+    let det = time * (time - total_time);
+    if det == 0.0 {
+        None
+    } else {
+        let gravity = 2.0 * (waypoint_height - launch_height) / det;
+        Some((-0.5 * gravity * total_time, gravity))
+    }
+}
+
+/// Apply a jump parameter function to each pair of values of two slices
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+fn map_slices(first: &[f32], second: &[f32], out: &mut [f32], func: fn(f32, f32) -> f32) {
+    assert!(
+        first.len() == out.len() && second.len() == out.len(),
+        "the input and output slices must have the same length"
+    );
+    for ((out, &first), &second) in out.iter_mut().zip(first).zip(second) {
+        *out = func(first, second);
+    }
+}
+
+/// Compute the vertical impulses from the peak heights and the times to reach the peak,
+/// without allocating
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+pub fn impulse_from_height_and_time_slice(heights: &[f32], times: &[f32], out: &mut [f32]) {
+    map_slices(heights, times, out, impulse_from_height_and_time);
+}
+
+/// Compute the gravities from the peak heights and the times to reach the peak,
+/// without allocating
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+pub fn gravity_from_height_and_time_slice(heights: &[f32], times: &[f32], out: &mut [f32]) {
+    map_slices(heights, times, out, gravity_from_height_and_time);
+}
+
+/// Compute the peak heights from the vertical impulses and the gravities,
+/// without allocating
+///
+/// # Panics
+///
+/// Panics if the slices do not all have the same length.
+pub fn height_from_impulse_and_gravity_slice(impulses: &[f32], gravities: &[f32], out: &mut [f32]) {
+    map_slices(impulses, gravities, out, height_from_impulse_and_gravity);
+}
+
+/// Fill parallel slices of vertical impulses and gravities
+/// from the peak heights and the times to reach the peak, in a single pass
+///
+/// Returns `false` without writing anything when the slices do not all have the same length
+/// or when one of the times is zero.
+#[must_use]
+pub fn build_soa(
+    heights: &[f32],
+    times: &[f32],
+    out_impulse: &mut [f32],
+    out_gravity: &mut [f32],
+) -> bool {
+    let len = heights.len();
+    if times.len() != len || out_impulse.len() != len || out_gravity.len() != len {
+        return false;
+    }
+    if times.contains(&0.0) {
+        return false;
+    }
+    let outputs = out_impulse.iter_mut().zip(out_gravity.iter_mut());
+    for ((impulse, gravity), (&height, &time)) in outputs.zip(heights.iter().zip(times)) {
+        *impulse = impulse_from_height_and_time(height, time);
+        *gravity = gravity_from_height_and_time(height, time);
+    }
+    true
+}
+
+/// Replace an infinite or undefined result by zero
+#[inline]
+const fn saturate(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+/// Compute the peak height from the time to reach the peak and the vertical impulse,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_impulse_saturating(time: f32, impulse: f32) -> f32 {
+    saturate(height_from_time_and_impulse(time, impulse))
+}
+
+/// Compute the peak height from the time to reach the peak and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_time_and_gravity_saturating(time: f32, gravity: f32) -> f32 {
+    saturate(height_from_time_and_gravity(time, gravity))
+}
+
+/// Compute the peak height from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_impulse_and_gravity_saturating(impulse: f32, gravity: f32) -> f32 {
+    saturate(height_from_impulse_and_gravity(impulse, gravity))
+}
+
+/// Compute time to reach the peak from the peak height and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_impulse_saturating(height: f32, impulse: f32) -> f32 {
+    saturate(time_from_height_and_impulse(height, impulse))
+}
+
+/// Compute time to reach the peak from the peak height and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_height_and_gravity_saturating(height: f32, gravity: f32) -> f32 {
+    saturate(time_from_height_and_gravity(height, gravity))
+}
+
+/// Compute time to reach the peak from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_impulse_and_gravity_saturating(impulse: f32, gravity: f32) -> f32 {
+    saturate(time_from_impulse_and_gravity(impulse, gravity))
+}
+
+/// Compute the vertical impulse from the peak height and the time to reach the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_time_saturating(height: f32, time: f32) -> f32 {
+    saturate(impulse_from_height_and_time(height, time))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_height_and_gravity_saturating(height: f32, gravity: f32) -> f32 {
+    saturate(impulse_from_height_and_gravity(height, gravity))
+}
+
+/// Compute the vertical impulse from the peak height and the gravity,
+/// directed against the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn signed_impulse_from_height_and_gravity_saturating(height: f32, gravity: f32) -> f32 {
+    saturate(signed_impulse_from_height_and_gravity(height, gravity))
+}
+
+/// Compute the vertical impulse from the time to reach the peak and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn impulse_from_time_and_gravity_saturating(time: f32, gravity: f32) -> f32 {
+    saturate(impulse_from_time_and_gravity(time, gravity))
+}
+
+/// Compute the gravity from the peak height and the time to reach the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_time_saturating(height: f32, time: f32) -> f32 {
+    saturate(gravity_from_height_and_time(height, time))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_and_impulse_saturating(height: f32, impulse: f32) -> f32 {
+    saturate(gravity_from_height_and_impulse(height, impulse))
+}
+
+/// Compute the gravity from the peak height and the vertical impulse,
+/// directed against the impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn signed_gravity_from_height_and_impulse_saturating(height: f32, impulse: f32) -> f32 {
+    saturate(signed_gravity_from_height_and_impulse(height, impulse))
+}
+
+/// Compute the gravity from the time to reach the peak and the vertical impulse, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_time_and_impulse_saturating(time: f32, impulse: f32) -> f32 {
+    saturate(gravity_from_time_and_impulse(time, impulse))
+}
+
+/// Compute the time to reach the peak from the horizontal speed and the range, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range_saturating(speed: f32, range: f32) -> f32 {
+    saturate(time_from_speed_and_range(speed, range))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the horizontal speed,
+/// the range and the ratio of the flight spent ascending, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn time_from_speed_and_range_with_ratio_saturating(
+    speed: f32,
+    range: f32,
+    ratio: f32,
+) -> (f32, f32) {
+    let (ascend, descend) = time_from_speed_and_range_with_ratio(speed, range, ratio);
+    (saturate(ascend), saturate(descend))
+}
+
+/// Compute the time to reach the peak and the time to fall back from the peak height,
+/// the gravity while ascending and the gravity while descending, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn ascent_and_fall_times_saturating(
+    height: f32,
+    gravity_up: f32,
+    gravity_down: f32,
+) -> (f32, f32) {
+    let (ascend, descend) = ascent_and_fall_times(height, gravity_up, gravity_down);
+    (saturate(ascend), saturate(descend))
+}
+
+/// Compute the vertical impulse, the gravity and the time to reach the peak
+/// from the horizontal speed, the range and the peak height, zero instead of non-finite values
+#[inline]
+#[must_use]
+pub const fn trajectory_from_speed_range_and_height_saturating(
+    speed: f32,
+    range: f32,
+    height: f32,
+) -> (f32, f32, f32) {
+    let (impulse, gravity, time) = trajectory_from_speed_range_and_height(speed, range, height);
+    (saturate(impulse), saturate(gravity), saturate(time))
+}
+
+/// Compute the peak height from the horizontal speed, the range and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn height_from_speed_range_and_gravity_saturating(
+    speed: f32,
+    range: f32,
+    gravity: f32,
+) -> f32 {
+    saturate(height_from_speed_range_and_gravity(speed, range, gravity))
+}
+
+/// Compute the gravity from the peak height and the time spent within a threshold of the peak,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_for_hang_time_saturating(
+    height: f32,
+    hang_time: f32,
+    hang_threshold: f32,
+) -> f32 {
+    saturate(gravity_for_hang_time(height, hang_time, hang_threshold))
+}
+
+/// Compute the gravity from the peak height, the horizontal speed
+/// and the horizontal distance to the peak, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn gravity_from_height_speed_and_apex_distance_saturating(
+    height: f32,
+    speed: f32,
+    apex_distance: f32,
+) -> f32 {
+    saturate(gravity_from_height_speed_and_apex_distance(
+        height,
+        speed,
+        apex_distance,
+    ))
+}
+
+/// Compute the vertical velocity at a given time from the vertical impulse and the gravity,
+/// zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn velocity_at_time_saturating(impulse: f32, gravity: f32, time: f32) -> f32 {
+    saturate(velocity_at_time(impulse, gravity, time))
+}
+
+/// Compute the vertical kinetic energy per unit of mass at a given time
+/// from the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn kinetic_proxy_saturating(impulse: f32, gravity: f32, time: f32) -> f32 {
+    saturate(kinetic_proxy(impulse, gravity, time))
+}
+
+/// Compute the vertical mechanical energy at a given time from the mass,
+/// the vertical impulse and the gravity, zero instead of a non-finite value
+#[inline]
+#[must_use]
+pub const fn total_energy_saturating(mass: f32, impulse: f32, gravity: f32, time: f32) -> f32 {
+    saturate(total_energy(mass, impulse, gravity, time))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -165,10 +798,651 @@ mod tests {
         const IMPULSE: f32 = impulse_from_height_and_time(HEIGHT, TIME);
         const GRAVITY: f32 = gravity_from_height_and_time(HEIGHT, TIME);
 
-        const TIME2: f32 = time_from_height_and_gravity_const(20.0, GRAVITY);
+        const TIME2: f32 = time_from_height_and_gravity(20.0, GRAVITY);
 
         assert_eq!(IMPULSE, 4.0);
         assert_eq!(GRAVITY, -0.4);
         assert_eq!(TIME2, 10.0);
     }
+
+    #[test]
+    fn test_time_with_ratio() {
+        use super::*;
+
+        const SPEED: f32 = 4.0;
+        const RANGE: f32 = 40.0;
+        const TIME: f32 = time_from_speed_and_range(SPEED, RANGE);
+
+        // a symmetric jump spends half of the flight on each side of the peak
+        let (ascend, descend) = time_from_speed_and_range_with_ratio(SPEED, RANGE, 0.5);
+        assert_eq!(ascend, TIME);
+        assert_eq!(descend, TIME);
+
+        // an asymmetric jump still covers the full flight
+        let (ascend, descend) = time_from_speed_and_range_with_ratio(SPEED, RANGE, 0.25);
+        assert_eq!(ascend, 2.5);
+        assert_eq!(descend, 7.5);
+        assert_eq!(ascend + descend, 2.0 * TIME);
+    }
+
+    #[test]
+    fn test_height_from_speed_range_and_gravity() {
+        use super::*;
+
+        const SPEED: f32 = 4.0;
+        const RANGE: f32 = 80.0;
+        const GRAVITY: f32 = -0.4;
+        const HEIGHT: f32 = height_from_speed_range_and_gravity(SPEED, RANGE, GRAVITY);
+
+        let time = time_from_speed_and_range(SPEED, RANGE);
+        assert_eq!(HEIGHT, height_from_time_and_gravity(time, GRAVITY));
+        assert_eq!(HEIGHT, 20.0);
+
+        // without horizontal speed the target is never reached
+        assert_eq!(
+            height_from_speed_range_and_gravity(0.0, RANGE, GRAVITY),
+            f32::INFINITY
+        );
+    }
+
+    /// Assert that the jump reaches its peak height when the vertical velocity becomes null
+    fn assert_apex(height: f32, time: f32, impulse: f32, gravity: f32) {
+        const TOLERANCE: f32 = 1e-5;
+
+        let velocity = impulse + gravity * time;
+        let position = impulse * time + 0.5 * gravity * time * time;
+
+        assert!(
+            velocity.abs() <= TOLERANCE * impulse.abs(),
+            "non-null velocity {velocity} at the peak"
+        );
+        assert!(
+            (position - height).abs() <= TOLERANCE * height.abs(),
+            "peak reached at {position} instead of {height}"
+        );
+    }
+
+    #[test]
+    fn test_apex_invariant() {
+        use super::*;
+
+        for (height, time) in [(20.0, 10.0), (3.0, 0.7), (125.0, 1.3)] {
+            let impulse = impulse_from_height_and_time(height, time);
+            let gravity = gravity_from_height_and_time(height, time);
+            assert_apex(height, time, impulse, gravity);
+
+            // every pair of known parameters must give back the same jump
+            #[rustfmt::skip]
+            let jumps = [
+                (height, time, impulse_from_height_and_time(height, time), gravity_from_height_and_time(height, time)),
+                (height, time_from_height_and_impulse(height, impulse), impulse, gravity_from_height_and_impulse(height, impulse)),
+                (height, time_from_height_and_gravity(height, gravity), impulse_from_height_and_gravity(height, gravity), gravity),
+                (height_from_time_and_impulse(time, impulse), time, impulse, gravity_from_time_and_impulse(time, impulse)),
+                (height_from_time_and_gravity(time, gravity), time, impulse_from_time_and_gravity(time, gravity), gravity),
+                (height_from_impulse_and_gravity(impulse, gravity), time_from_impulse_and_gravity(impulse, gravity), impulse, gravity),
+            ];
+            for (height, time, impulse, gravity) in jumps {
+                assert_apex(height, time, impulse, gravity);
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        use super::*;
+
+        const COUNT: usize = 1024;
+
+        let heights: [f32; COUNT] = core::array::from_fn(|index| 1.0 + index as f32);
+        let times: [f32; COUNT] = core::array::from_fn(|index| 0.5 + (index % 16) as f32);
+        let mut out = [0.0; COUNT];
+
+        // each output is the result of the scalar function
+        impulse_from_height_and_time_slice(&heights, &times, &mut out);
+        for index in 0..COUNT {
+            assert_eq!(
+                out[index],
+                impulse_from_height_and_time(heights[index], times[index])
+            );
+        }
+
+        gravity_from_height_and_time_slice(&heights, &times, &mut out);
+        for index in 0..COUNT {
+            assert_eq!(
+                out[index],
+                gravity_from_height_and_time(heights[index], times[index])
+            );
+        }
+
+        let gravities = out;
+        height_from_impulse_and_gravity_slice(&times, &gravities, &mut out);
+        for index in 0..COUNT {
+            assert_eq!(
+                out[index],
+                height_from_impulse_and_gravity(times[index], gravities[index])
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_length_mismatch() {
+        use super::*;
+
+        let mut out = [0.0; 2];
+        impulse_from_height_and_time_slice(&[20.0, 10.0], &[10.0], &mut out);
+    }
+
+    #[test]
+    fn test_build_soa() {
+        use super::*;
+
+        let heights = [20.0, 10.0, 5.0, 40.0];
+        let times = [10.0, 4.0, 2.0, 8.0];
+        let mut impulses = [0.0; 4];
+        let mut gravities = [0.0; 4];
+
+        assert!(build_soa(&heights, &times, &mut impulses, &mut gravities));
+        for index in 0..4 {
+            let (height, time) = (heights[index], times[index]);
+            assert_eq!(impulses[index], impulse_from_height_and_time(height, time));
+            assert_eq!(gravities[index], gravity_from_height_and_time(height, time));
+        }
+
+        // nothing is written on invalid inputs
+        let mut impulses = [1.0; 4];
+        assert!(!build_soa(
+            &heights,
+            &times[..3],
+            &mut impulses,
+            &mut gravities
+        ));
+        assert!(!build_soa(
+            &heights,
+            &[10.0, 0.0, 2.0, 8.0],
+            &mut impulses,
+            &mut gravities
+        ));
+        assert_eq!(impulses, [1.0; 4]);
+    }
+
+    #[test]
+    fn test_saturating() {
+        use super::*;
+
+        // null divisors and values large enough to overflow
+        const VALUES: [f32; 7] = [0.0, -0.0, 1.0, -2.5, f32::MAX, f32::MIN, f32::MIN_POSITIVE];
+
+        for a in VALUES {
+            for b in VALUES {
+                #[rustfmt::skip]
+                let results = [
+                    height_from_time_and_impulse_saturating(a, b),
+                    height_from_time_and_gravity_saturating(a, b),
+                    height_from_impulse_and_gravity_saturating(a, b),
+                    time_from_height_and_impulse_saturating(a, b),
+                    time_from_height_and_gravity_saturating(a, b),
+                    time_from_impulse_and_gravity_saturating(a, b),
+                    impulse_from_height_and_time_saturating(a, b),
+                    impulse_from_height_and_gravity_saturating(a, b),
+                    signed_impulse_from_height_and_gravity_saturating(a, b),
+                    impulse_from_time_and_gravity_saturating(a, b),
+                    gravity_from_height_and_time_saturating(a, b),
+                    gravity_from_height_and_impulse_saturating(a, b),
+                    signed_gravity_from_height_and_impulse_saturating(a, b),
+                    gravity_from_time_and_impulse_saturating(a, b),
+                    time_from_speed_and_range_saturating(a, b),
+                    time_from_speed_and_range_with_ratio_saturating(a, b, 0.5).0,
+                    time_from_speed_and_range_with_ratio_saturating(a, b, 0.5).1,
+                    ascent_and_fall_times_saturating(a, b, b).0,
+                    ascent_and_fall_times_saturating(a, b, b).1,
+                    trajectory_from_speed_range_and_height_saturating(a, b, 1.0).0,
+                    trajectory_from_speed_range_and_height_saturating(a, b, 1.0).1,
+                    trajectory_from_speed_range_and_height_saturating(a, b, 1.0).2,
+                    height_from_speed_range_and_gravity_saturating(a, b, -1.0),
+                    gravity_from_height_speed_and_apex_distance_saturating(a, b, 1.0),
+                    gravity_for_hang_time_saturating(a, b, 1.0),
+                    velocity_at_time_saturating(a, b, b),
+                    kinetic_proxy_saturating(a, b, b),
+                    total_energy_saturating(a, a, b, b),
+                ];
+                for result in results {
+                    assert!(result.is_finite(), "non-finite result for ({a}, {b})");
+                }
+            }
+        }
+
+        // valid inputs give the same result as the non-saturating versions
+        assert_eq!(impulse_from_height_and_time_saturating(20.0, 10.0), 4.0);
+        assert_eq!(impulse_from_height_and_time_saturating(20.0, 0.0), 0.0);
+        assert_eq!(height_from_time_and_impulse_saturating(10.0, 4.0), 20.0);
+        assert_eq!(
+            total_energy_saturating(2.0, 4.0, -0.5, 1.0),
+            total_energy(2.0, 4.0, -0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_trajectory_from_speed_range_and_height() {
+        use super::*;
+
+        const SPEED: f32 = 4.0;
+        const RANGE: f32 = 80.0;
+        const HEIGHT: f32 = 20.0;
+        const TRAJECTORY: (f32, f32, f32) =
+            trajectory_from_speed_range_and_height(SPEED, RANGE, HEIGHT);
+
+        let (impulse, gravity, time) = TRAJECTORY;
+        assert_eq!(TRAJECTORY, (4.0, -0.4, 10.0));
+
+        // the peak is reached over the middle of the range
+        let time_at_midpoint = 0.5 * RANGE / SPEED;
+        assert_eq!(time_at_midpoint, time);
+        let position =
+            impulse * time_at_midpoint + 0.5 * gravity * time_at_midpoint * time_at_midpoint;
+        assert!((position - HEIGHT).abs() <= 1e-5 * HEIGHT);
+
+        // without horizontal speed the range is never covered
+        assert_eq!(
+            trajectory_from_speed_range_and_height(0.0, RANGE, HEIGHT),
+            (f32::INFINITY, f32::NEG_INFINITY, f32::INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_velocity_at_time() {
+        use super::*;
+
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        const TIME: f32 = time_from_impulse_and_gravity(IMPULSE, GRAVITY);
+        const VELOCITY: f32 = velocity_at_time(IMPULSE, GRAVITY, TIME);
+        const ENERGY: f32 = kinetic_proxy(IMPULSE, GRAVITY, TIME);
+
+        // the body stops rising at the peak
+        assert_eq!(VELOCITY, 0.0);
+        assert_eq!(ENERGY, 0.0);
+        assert!(kinetic_proxy(IMPULSE, GRAVITY, TIME - 1.0) > ENERGY);
+        assert!(kinetic_proxy(IMPULSE, GRAVITY, TIME + 1.0) > ENERGY);
+
+        // it lands back at the speed it took off
+        assert_eq!(velocity_at_time(IMPULSE, GRAVITY, 2.0 * TIME), -IMPULSE);
+        assert_eq!(
+            kinetic_proxy(IMPULSE, GRAVITY, 2.0 * TIME),
+            0.5 * IMPULSE * IMPULSE
+        );
+    }
+
+    #[test]
+    fn test_solve_partial() {
+        use super::*;
+
+        const PARAMETERS: [f32; 4] = [20.0, 10.0, 4.0, -0.4];
+        let [h, t, i, g] = PARAMETERS.map(Some);
+
+        // any two known parameters give back the other two
+        #[rustfmt::skip]
+        let partials = [
+            [h   , t   , None, None],
+            [h   , None, i   , None],
+            [h   , None, None, g   ],
+            [None, t   , i   , None],
+            [None, t   , None, g   ],
+            [None, None, i   , g   ],
+        ];
+        for partial in partials {
+            let solved = solve_partial(partial).unwrap();
+            for (value, expected) in solved.into_iter().zip(PARAMETERS) {
+                assert!(
+                    (value - expected).abs() <= 1e-5 * expected.abs(),
+                    "{partial:?}"
+                );
+            }
+        }
+
+        // under or over specified parameters cannot be solved
+        assert_eq!(solve_partial([None; 4]), None);
+        assert_eq!(solve_partial([h, None, None, None]), None);
+        assert_eq!(solve_partial([h, t, i, None]), None);
+        assert_eq!(solve_partial([h, t, i, g]), None);
+    }
+
+    #[test]
+    fn test_solve_from_two_samples() {
+        use super::*;
+
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        let sample = |time: f32| (time, IMPULSE * time + 0.5 * GRAVITY * time * time);
+
+        // the samples may be on the same side of the peak or not
+        for (time1, time2) in [(1.0, 3.0), (2.0, 12.0), (14.0, 6.0)] {
+            let (impulse, gravity) = solve_from_two_samples(sample(time1), sample(time2)).unwrap();
+            assert!((impulse - IMPULSE).abs() <= 1e-5 * IMPULSE);
+            assert!((gravity - GRAVITY).abs() <= 1e-5 * -GRAVITY);
+        }
+
+        // the system cannot be solved with a single sample
+        assert_eq!(solve_from_two_samples(sample(2.0), sample(2.0)), None);
+        assert_eq!(solve_from_two_samples(sample(0.0), sample(2.0)), None);
+    }
+
+    #[test]
+    fn test_times_at_height() {
+        use super::*;
+
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        const HEIGHT: f32 = height_from_impulse_and_gravity(IMPULSE, GRAVITY);
+        const TIME: f32 = time_from_impulse_and_gravity(IMPULSE, GRAVITY);
+
+        // the jump leaves and lands back on the ground
+        assert_eq!(
+            times_at_height(IMPULSE, GRAVITY, 0.0),
+            Some((0.0, 2.0 * TIME))
+        );
+
+        // the peak is only reached once
+        assert_eq!(
+            times_at_height(IMPULSE, GRAVITY, HEIGHT),
+            Some((TIME, TIME))
+        );
+
+        // the two times are symmetric around the peak
+        let (ascending, descending) = times_at_height(IMPULSE, GRAVITY, 0.5 * HEIGHT).unwrap();
+        assert!((TIME - ascending - (descending - TIME)).abs() <= 1e-5 * TIME);
+
+        // the height below the launch is only reached on the way up before the launch
+        let (ascending, descending) = times_at_height(IMPULSE, GRAVITY, -HEIGHT).unwrap();
+        assert!(ascending < 0.0);
+        assert!(descending > 2.0 * TIME);
+        assert!((TIME - ascending - (descending - TIME)).abs() <= 1e-5 * TIME);
+
+        // the height above the peak is never reached
+        assert_eq!(times_at_height(IMPULSE, GRAVITY, 2.0 * HEIGHT), None);
+        assert_eq!(times_at_height(IMPULSE, 0.0, HEIGHT), None);
+    }
+
+    #[test]
+    fn test_gravity_from_height_speed_and_apex_distance() {
+        use super::*;
+
+        const HEIGHT: f32 = 20.0;
+        const SPEED: f32 = 4.0;
+        const APEX_DISTANCE: f32 = 40.0;
+        const GRAVITY: f32 =
+            gravity_from_height_speed_and_apex_distance(HEIGHT, SPEED, APEX_DISTANCE);
+        assert_eq!(GRAVITY, -0.4);
+
+        // the peak is reached right above the expected point
+        let impulse = impulse_from_height_and_gravity(HEIGHT, GRAVITY);
+        let time = time_from_impulse_and_gravity(impulse, GRAVITY);
+        assert!((SPEED * time - APEX_DISTANCE).abs() <= 1e-5 * APEX_DISTANCE);
+        let position = impulse * time + 0.5 * GRAVITY * time * time;
+        assert!((position - HEIGHT).abs() <= 1e-5 * HEIGHT);
+
+        // without horizontal speed the peak is never reached
+        assert_eq!(
+            gravity_from_height_speed_and_apex_distance(HEIGHT, 0.0, APEX_DISTANCE),
+            f32::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_time_to_height_ascending() {
+        use super::*;
+
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        const HEIGHT: f32 = height_from_impulse_and_gravity(IMPULSE, GRAVITY);
+        const TIME: f32 = time_from_impulse_and_gravity(IMPULSE, GRAVITY);
+
+        assert_eq!(time_to_height_ascending(IMPULSE, GRAVITY, 0.0), Some(0.0));
+        assert_eq!(
+            time_to_height_ascending(IMPULSE, GRAVITY, HEIGHT),
+            Some(TIME)
+        );
+        assert_eq!(
+            time_to_height_ascending(IMPULSE, GRAVITY, 2.0 * HEIGHT),
+            None
+        );
+
+        // a height below the launch is only crossed on the way down
+        assert_eq!(time_to_height_ascending(IMPULSE, GRAVITY, -5.0), None);
+
+        // halfway up is reached before halfway through the ascent
+        let time = time_to_height_ascending(IMPULSE, GRAVITY, 0.5 * HEIGHT).unwrap();
+        assert!(0.0 < time && time < 0.5 * TIME);
+        let position = IMPULSE * time + 0.5 * GRAVITY * time * time;
+        assert!((position - 0.5 * HEIGHT).abs() <= 1e-5 * HEIGHT);
+    }
+
+    #[test]
+    fn test_launch_velocity_for_target() {
+        use super::*;
+
+        const GRAVITY: f32 = -10.0;
+        const SPEED: f32 = 20.0;
+
+        for (dx, dy) in [(30.0, 0.0), (-30.0, 5.0), (10.0, -20.0), (0.0, 15.0)] {
+            let (horizontal, vertical) =
+                launch_velocity_for_target(GRAVITY, dx, dy, SPEED).unwrap();
+            assert!((pow2![horizontal] + pow2![vertical] - SPEED * SPEED).abs() <= 1e-3);
+
+            // the target is on the arc
+            if dx != 0.0 {
+                let time = dx / horizontal;
+                let position = vertical * time + 0.5 * GRAVITY * time * time;
+                assert!((position - dy).abs() <= 1e-3, "({dx}, {dy})");
+            }
+        }
+
+        // the lower arc is launched under 45 degrees on flat ground
+        let (horizontal, vertical) = launch_velocity_for_target(GRAVITY, 30.0, 0.0, SPEED).unwrap();
+        assert!(0.0 < vertical && vertical < horizontal);
+
+        // too far or too high for this speed
+        assert_eq!(launch_velocity_for_target(GRAVITY, 50.0, 0.0, SPEED), None);
+        assert_eq!(launch_velocity_for_target(GRAVITY, 0.0, 30.0, SPEED), None);
+        assert_eq!(launch_velocity_for_target(0.0, 30.0, 0.0, SPEED), None);
+    }
+
+    #[test]
+    fn test_ascent_and_fall_times() {
+        use super::*;
+
+        const HEIGHT: f32 = 20.0;
+        const GRAVITY_UP: f32 = -0.4;
+        const GRAVITY_DOWN: f32 = -1.6;
+        const TIMES: (f32, f32) = ascent_and_fall_times(HEIGHT, GRAVITY_UP, GRAVITY_DOWN);
+
+        // a stronger gravity makes the fall shorter than the ascent
+        let (ascend, descend) = TIMES;
+        assert_eq!(TIMES, (10.0, 5.0));
+
+        // both halves go through the same peak
+        assert_eq!(height_from_time_and_gravity(ascend, GRAVITY_UP), HEIGHT);
+        assert_eq!(height_from_time_and_gravity(descend, GRAVITY_DOWN), HEIGHT);
+
+        // without gravity the peak is never reached nor left
+        assert_eq!(
+            ascent_and_fall_times(HEIGHT, 0.0, GRAVITY_DOWN),
+            (f32::INFINITY, 5.0)
+        );
+        assert_eq!(
+            ascent_and_fall_times(HEIGHT, GRAVITY_UP, 0.0),
+            (10.0, f32::INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_solve_through_waypoint() {
+        use super::*;
+
+        const LAUNCH: (f32, f32) = (2.0, 5.0);
+        const TOTAL_TIME: f32 = 20.0;
+        let position = |(impulse, gravity): (f32, f32), time: f32| {
+            let time = time - LAUNCH.0;
+            LAUNCH.1 + impulse * time + 0.5 * gravity * time * time
+        };
+
+        for waypoint in [(4.0, 10.0), (12.0, 25.0), (19.0, 6.0)] {
+            let solution = solve_through_waypoint(LAUNCH, waypoint, TOTAL_TIME).unwrap();
+            let (impulse, gravity) = solution;
+            assert!(impulse > 0.0 && gravity < 0.0);
+
+            // the arc goes through the waypoint and lands back at the launch height
+            assert!((position(solution, waypoint.0) - waypoint.1).abs() <= 1e-4);
+            assert!((position(solution, LAUNCH.0 + TOTAL_TIME) - LAUNCH.1).abs() <= 1e-4);
+        }
+
+        // the waypoint cannot be at the launch nor at the landing
+        assert_eq!(
+            solve_through_waypoint(LAUNCH, (2.0, 10.0), TOTAL_TIME),
+            None
+        );
+        assert_eq!(
+            solve_through_waypoint(LAUNCH, (22.0, 10.0), TOTAL_TIME),
+            None
+        );
+    }
+
+    #[test]
+    fn test_signed_impulse_from_height_and_gravity() {
+        use super::*;
+
+        // upward axis, the jump goes up
+        assert_eq!(signed_impulse_from_height_and_gravity(20.0, -0.4), 4.0);
+
+        // downward axis, the same jump goes toward negative values
+        assert_eq!(signed_impulse_from_height_and_gravity(-20.0, 0.4), -4.0);
+
+        // both reach the peak at the same time
+        assert_eq!(
+            time_from_impulse_and_gravity(4.0, -0.4),
+            time_from_impulse_and_gravity(-4.0, 0.4)
+        );
+    }
+
+    #[test]
+    fn test_signed_gravity_from_height_and_impulse() {
+        use super::*;
+
+        // upward axis, the gravity pulls toward negative values
+        assert_eq!(signed_gravity_from_height_and_impulse(20.0, 4.0), -0.4);
+
+        // downward axis, the same gravity pulls toward positive values
+        assert_eq!(signed_gravity_from_height_and_impulse(-20.0, -4.0), 0.4);
+        assert_eq!(signed_gravity_from_height_and_impulse(20.0, -4.0), 0.4);
+
+        // the signed impulse and gravity describe the same jump on both axes
+        for (height, gravity) in [(20.0, -0.4), (-20.0, 0.4)] {
+            let impulse = signed_impulse_from_height_and_gravity(height, gravity);
+            assert_eq!(
+                signed_gravity_from_height_and_impulse(height, impulse),
+                gravity
+            );
+        }
+
+        // without a height the gravity is infinite, still against the impulse
+        assert_eq!(
+            signed_gravity_from_height_and_impulse(0.0, 4.0),
+            f32::NEG_INFINITY
+        );
+        assert_eq!(
+            signed_gravity_from_height_and_impulse(0.0, -4.0),
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_time_to_land_from_offset() {
+        use super::*;
+
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        const LAUNCH: f32 = 10.0;
+        const TIME: f32 = time_from_impulse_and_gravity(IMPULSE, GRAVITY);
+        let landing = |target| time_to_land_from_offset(IMPULSE, GRAVITY, LAUNCH, target);
+
+        // landing at the launch height takes the full flight
+        assert_eq!(landing(LAUNCH), Some(2.0 * TIME));
+
+        // landing below takes longer, landing above is quicker
+        assert!(landing(LAUNCH - 5.0).unwrap() > 2.0 * TIME);
+        assert!(landing(LAUNCH + 5.0).unwrap() < 2.0 * TIME);
+        for target in [LAUNCH - 5.0, LAUNCH + 5.0] {
+            let time = landing(target).unwrap();
+            let position = LAUNCH + IMPULSE * time + 0.5 * GRAVITY * time * time;
+            assert!((position - target).abs() <= 1e-4);
+        }
+
+        // the target is above the peak or the jump never comes back
+        assert_eq!(landing(LAUNCH + 20.0), None);
+        assert_eq!(time_to_land_from_offset(IMPULSE, 0.0, LAUNCH, LAUNCH), None);
+    }
+
+    #[test]
+    fn test_gravity_for_hang_time() {
+        use super::*;
+
+        const HEIGHT: f32 = 20.0;
+        const THRESHOLD: f32 = 2.0;
+
+        let mut previous = f32::NEG_INFINITY;
+        for hang_time in [1.0, 2.0, 4.0] {
+            // a longer hang time needs a weaker gravity
+            let gravity = gravity_for_hang_time(HEIGHT, hang_time, THRESHOLD);
+            assert!(previous < gravity && gravity < 0.0);
+            previous = gravity;
+
+            // the jump spends the hang time above the threshold
+            let impulse = impulse_from_height_and_gravity(HEIGHT, gravity);
+            let (enter, leave) = times_at_height(impulse, gravity, HEIGHT - THRESHOLD).unwrap();
+            assert!((leave - enter - hang_time).abs() <= 1e-4 * hang_time);
+        }
+
+        // a threshold above the peak covers the whole jump
+        let gravity = gravity_for_hang_time(HEIGHT, 10.0, 2.0 * HEIGHT);
+        assert_eq!(gravity, gravity_from_height_and_time(HEIGHT, 5.0));
+        assert_eq!(
+            gravity_for_hang_time(HEIGHT, 0.0, THRESHOLD),
+            f32::NEG_INFINITY
+        );
+
+        // the jump cannot be held within a null or negative threshold
+        for threshold in [0.0, -THRESHOLD] {
+            assert_eq!(
+                gravity_for_hang_time(HEIGHT, 4.0, threshold),
+                f32::NEG_INFINITY
+            );
+            assert_eq!(
+                gravity_for_hang_time_saturating(HEIGHT, 4.0, threshold),
+                0.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_total_energy() {
+        use super::*;
+
+        const MASS: f32 = 2.0;
+        const IMPULSE: f32 = 4.0;
+        const GRAVITY: f32 = -0.5;
+        const ENERGY: f32 = total_energy(MASS, IMPULSE, GRAVITY, 0.0);
+        assert_eq!(ENERGY, MASS * 0.5 * IMPULSE * IMPULSE);
+
+        // the energy is conserved along the jump
+        for time in [1.0, 4.0, 8.0, 13.0, 16.0] {
+            let energy = total_energy(MASS, IMPULSE, GRAVITY, time);
+            assert!((energy - ENERGY).abs() <= 1e-5 * ENERGY, "at {time}");
+        }
+
+        // the same jump with a downward vertical axis
+        for time in [0.0, 1.0, 4.0, 8.0, 13.0, 16.0] {
+            let energy = total_energy(MASS, -IMPULSE, -GRAVITY, time);
+            assert!((energy - ENERGY).abs() <= 1e-5 * ENERGY, "at {time}");
+        }
+    }
 }
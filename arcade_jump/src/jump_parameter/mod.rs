@@ -1,8 +1,16 @@
+/// Computation of jump parameter with 16 bits floating point numbers
+#[cfg(feature = "half")]
+pub mod float16;
+
 /// Computation of jump parameter with 32 bits floating point numbers
 pub mod float32;
 
 /// Computation of jump parameter with 64 bits floating point numbers
 pub mod float64;
 
+/// Computation of jump parameter on SIMD vectors of floating point numbers
+#[cfg(feature = "simd")]
+pub mod simd;
+
 /// Macro re-export
-pub use arcade_jump_macros::jump_parameters;
+pub use arcade_jump_macros::{all_jump_parameters, jump_parameters};
@@ -0,0 +1,170 @@
+use wide::{f32x4, f32x8, f64x2, f64x4};
+
+/// Generate the lane-wise jump parameter functions for a SIMD vector type
+macro_rules! simd_jump_parameter {
+    ($module:ident, $vector:ident, $float:ident) => {
+        #[doc = concat!("Computation of jump parameter on `", stringify!($vector), "` lanes")]
+        pub mod $module {
+            use super::*;
+
+            /// Replace the lanes where the divisor is null by a fallback value
+            #[inline]
+            fn guard(divisor: $vector, fallback: $float, value: $vector) -> $vector {
+                divisor
+                    .simd_eq($vector::ZERO)
+                    .bitselect($vector::splat(fallback), value)
+            }
+
+            /// Compute the peak height from the time to reach the peak and the vertical impulse
+            #[inline]
+            #[must_use]
+            pub fn height_from_time_and_impulse(time: $vector, impulse: $vector) -> $vector {
+                $vector::splat(0.5) * impulse * time
+            }
+
+            /// Compute the peak height from the time to reach the peak and the gravity
+            #[inline]
+            #[must_use]
+            pub fn height_from_time_and_gravity(time: $vector, gravity: $vector) -> $vector {
+                $vector::splat(-0.5) * gravity * (time * time)
+            }
+
+            /// Compute the peak height from the vertical impulse and the gravity
+            #[inline]
+            #[must_use]
+            pub fn height_from_impulse_and_gravity(impulse: $vector, gravity: $vector) -> $vector {
+                let height = $vector::splat(-0.5) * (impulse * impulse) / gravity;
+                guard(gravity, $float::INFINITY, height)
+            }
+
+            /// Compute time to reach the peak from the peak height and the vertical impulse
+            #[inline]
+            #[must_use]
+            pub fn time_from_height_and_impulse(height: $vector, impulse: $vector) -> $vector {
+                let time = $vector::splat(2.0) * height / impulse;
+                guard(impulse, $float::INFINITY, time)
+            }
+
+            /// Compute time to reach the peak from the peak height and the gravity
+            #[inline]
+            #[must_use]
+            pub fn time_from_height_and_gravity(height: $vector, gravity: $vector) -> $vector {
+                let time = ($vector::splat(2.0) * height / gravity).abs().sqrt();
+                guard(gravity, $float::INFINITY, time)
+            }
+
+            /// Compute time to reach the peak from the vertical impulse and the gravity
+            #[inline]
+            #[must_use]
+            pub fn time_from_impulse_and_gravity(impulse: $vector, gravity: $vector) -> $vector {
+                guard(gravity, $float::INFINITY, -impulse / gravity)
+            }
+
+            /// Compute the vertical impulse from the peak height and the time to reach the peak
+            #[inline]
+            #[must_use]
+            pub fn impulse_from_height_and_time(height: $vector, time: $vector) -> $vector {
+                let impulse = $vector::splat(2.0) * height / time;
+                guard(time, $float::INFINITY, impulse)
+            }
+
+            /// Compute the vertical impulse from the peak height and the gravity
+            #[inline]
+            #[must_use]
+            pub fn impulse_from_height_and_gravity(height: $vector, gravity: $vector) -> $vector {
+                ($vector::splat(2.0) * height * gravity).abs().sqrt()
+            }
+
+            /// Compute the vertical impulse from the time to reach the peak and the gravity
+            #[inline]
+            #[must_use]
+            pub fn impulse_from_time_and_gravity(time: $vector, gravity: $vector) -> $vector {
+                -gravity * time
+            }
+
+            /// Compute the gravity from the peak height and the time to reach the peak
+            #[inline]
+            #[must_use]
+            pub fn gravity_from_height_and_time(height: $vector, time: $vector) -> $vector {
+                let gravity = $vector::splat(-2.0) * height / (time * time);
+                guard(time, $float::NEG_INFINITY, gravity)
+            }
+
+            /// Compute the gravity from the peak height and the vertical impulse
+            #[inline]
+            #[must_use]
+            pub fn gravity_from_height_and_impulse(height: $vector, impulse: $vector) -> $vector {
+                let gravity = $vector::splat(-0.5) * (impulse * impulse) / height;
+                guard(height, $float::NEG_INFINITY, gravity)
+            }
+
+            /// Compute the gravity from the time to reach the peak and the vertical impulse
+            #[inline]
+            #[must_use]
+            pub fn gravity_from_time_and_impulse(time: $vector, impulse: $vector) -> $vector {
+                guard(time, $float::NEG_INFINITY, -impulse / time)
+            }
+        }
+    };
+}
+
+simd_jump_parameter!(float32x4, f32x4, f32);
+simd_jump_parameter!(float32x8, f32x8, f32);
+simd_jump_parameter!(float64x2, f64x2, f64);
+simd_jump_parameter!(float64x4, f64x4, f64);
+
+#[cfg(test)]
+mod tests {
+
+    /// Compare every function of a SIMD module to its scalar version, lane by lane
+    macro_rules! assert_lane_wise {
+        ($module:ident, $scalar:ident, $vector:ident, $float:ident) => {{
+            use super::$module::*;
+            use crate::jump_parameter::$scalar;
+            use wide::$vector;
+
+            // every parameter is null on at least one lane
+            let heights = $vector::new(core::array::from_fn(|lane| 5.0 * lane as $float));
+            let times = $vector::new(core::array::from_fn(|lane| 0.5 * lane as $float));
+            let impulses = $vector::new(core::array::from_fn(|lane| lane as $float - 1.0));
+            let gravities = $vector::new(core::array::from_fn(|lane| -0.25 * lane as $float));
+
+            macro_rules! check {
+                ($func:ident, $param1:ident, $param2:ident) => {
+                    let result = $func($param1, $param2).to_array();
+                    let (param1, param2) = ($param1.to_array(), $param2.to_array());
+                    for lane in 0..result.len() {
+                        assert_eq!(
+                            result[lane],
+                            $scalar::$func(param1[lane], param2[lane]),
+                            "{} on lane {}",
+                            stringify!($func),
+                            lane
+                        );
+                    }
+                };
+            }
+
+            check!(height_from_time_and_impulse, times, impulses);
+            check!(height_from_time_and_gravity, times, gravities);
+            check!(height_from_impulse_and_gravity, impulses, gravities);
+            check!(time_from_height_and_impulse, heights, impulses);
+            check!(time_from_height_and_gravity, heights, gravities);
+            check!(time_from_impulse_and_gravity, impulses, gravities);
+            check!(impulse_from_height_and_time, heights, times);
+            check!(impulse_from_height_and_gravity, heights, gravities);
+            check!(impulse_from_time_and_gravity, times, gravities);
+            check!(gravity_from_height_and_time, heights, times);
+            check!(gravity_from_height_and_impulse, heights, impulses);
+            check!(gravity_from_time_and_impulse, times, impulses);
+        }};
+    }
+
+    #[test]
+    fn test_lane_wise() {
+        assert_lane_wise!(float32x4, float32, f32x4, f32);
+        assert_lane_wise!(float32x8, float32, f32x8, f32);
+        assert_lane_wise!(float64x2, float64, f64x2, f64);
+        assert_lane_wise!(float64x4, float64, f64x4, f64);
+    }
+}
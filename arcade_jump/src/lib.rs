@@ -1,20 +1,61 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![feature(const_fn_floating_point_arithmetic)]
 
 /// Compute the trajectory of a jump
 pub mod jump_parameter;
 
+/// Step by step simulation of a jump
+pub mod sim;
+
+/// C bindings of the jump parameter functions
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 mod math {
 
     /// Compute the square of a value
+    ///
+    /// The value is evaluated once, so it has to be `Copy` to be multiplied by itself.
     macro_rules! pow2 {
-        ($var:ident) => {
-            $var * $var
-        };
         ($val:expr) => {{
             let val = $val;
             val * val
         }};
     }
     pub(crate) use pow2;
+
+    #[cfg(test)]
+    mod tests {
+        use core::ops::Mul;
+
+        /// Number only implementing the multiplication by value
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct Fixed(i32);
+
+        impl Mul for Fixed {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self(self.0 * other.0 / 100)
+            }
+        }
+
+        #[test]
+        fn test_pow2() {
+            let value = Fixed(250);
+            assert_eq!(pow2![value], Fixed(625));
+            assert_eq!(pow2![Fixed(value.0 * 2)], Fixed(2500));
+
+            // the expression is only evaluated once
+            let mut count = 0;
+            let square = pow2![{
+                count += 1;
+                Fixed(300)
+            }];
+            assert_eq!((square, count), (Fixed(900), 1));
+
+            // references multiply as well
+            let value = &3.0_f32;
+            assert_eq!(pow2![*value], 9.0);
+        }
+    }
 }
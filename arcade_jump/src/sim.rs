@@ -0,0 +1,70 @@
+use core::ops::{Add, Div, Mul};
+
+/// Integrate the motion of a body starting at the origin with an initial velocity `(x, y)`
+/// under a constant acceleration `(x, y)`, such as a gravity and a wind
+///
+/// Yields the position and the velocity after each of the `steps` time steps `dt`.
+/// The position is integrated with the average velocity over the step,
+/// which matches the exact trajectory under a constant acceleration.
+pub fn integrate_2d<N>(
+    initial_vel: (N, N),
+    accel: (N, N),
+    dt: N,
+    steps: usize,
+) -> impl Iterator<Item = ((N, N), (N, N))>
+where
+    N: Copy + Default + From<u8> + Add<Output = N> + Mul<Output = N> + Div<Output = N>,
+{
+    let step = move |(position, velocity): ((N, N), (N, N))| {
+        let next = (velocity.0 + accel.0 * dt, velocity.1 + accel.1 * dt);
+        let two = N::from(2);
+        let position = (
+            position.0 + (velocity.0 + next.0) * dt / two,
+            position.1 + (velocity.1 + next.1) * dt / two,
+        );
+        (position, next)
+    };
+
+    let start = ((N::default(), N::default()), initial_vel);
+    core::iter::successors(Some(start), move |&state| Some(step(state)))
+        .skip(1)
+        .take(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrate_2d() {
+        const SPEED: f64 = 2.0;
+        const IMPULSE: f64 = 4.0;
+        const GRAVITY: f64 = -0.4;
+        const DT: f64 = 0.5;
+
+        // without wind the horizontal motion is linear
+        let states = integrate_2d((SPEED, IMPULSE), (0.0, GRAVITY), DT, 40);
+        for (index, ((x, y), (vx, vy))) in states.enumerate() {
+            let time = DT * (index + 1) as f64;
+            assert_eq!((x, vx), (SPEED * time, SPEED));
+            assert!((y - (IMPULSE * time + 0.5 * GRAVITY * time * time)).abs() <= 1e-9);
+            assert!((vy - (IMPULSE + GRAVITY * time)).abs() <= 1e-9);
+        }
+
+        // the wind makes the jump drift and lands it further
+        const WIND: f64 = 0.1;
+        let ((x, y), (vx, _)) = integrate_2d((SPEED, IMPULSE), (WIND, GRAVITY), DT, 40)
+            .last()
+            .unwrap();
+        let time = DT * 40.0;
+        assert!(y.abs() <= 1e-9);
+        assert!((x - (SPEED * time + 0.5 * WIND * time * time)).abs() <= 1e-9);
+        assert!(x > SPEED * time && vx > SPEED);
+
+        // the number of steps is respected
+        assert_eq!(
+            integrate_2d((SPEED, IMPULSE), (0.0, GRAVITY), DT, 3).count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use arcade_jump::jump_parameter::jump_parameters;
+
+// Constants computed from literals do not leave temporary items in the module
+jump_parameters![
+    use const f32;
+    20.0: Height, 10.0: Time => IMPULSE: Impulse;
+];
+jump_parameters![
+    use const f32;
+    10.0: Height, 10.0: Time => LOWER_IMPULSE: Impulse;
+];
+
+#[test]
+fn test_module_constants() {
+    assert_eq!(IMPULSE, 4.0);
+    assert_eq!(LOWER_IMPULSE, 2.0);
+}
+
+#[test]
+fn test_temporaries_do_not_shadow() {
+    // named like the temporary holding the time of the first statement
+    let __time0: f32 = 10.0;
+
+    jump_parameters![
+        use f32;
+        (__time0 * 2.0): Time, 20.0: Height => slow_impulse: Impulse;
+    ];
+    jump_parameters![
+        use f32;
+        __time0: Time, 20.0: Height => impulse: Impulse;
+    ];
+    assert_eq!(slow_impulse, 2.0);
+    assert_eq!(impulse, 4.0);
+    assert_eq!(__time0, 10.0);
+}
+
+/// Jump settings of a character
+#[derive(Default)]
+struct Character {
+    impulse: f32,
+}
+
+#[test]
+fn test_assignment_does_not_shadow() {
+    // named like the field the output is assigned to
+    let impulse: f32 = 1.0;
+    let mut character = Character::default();
+
+    jump_parameters![
+        use f32;
+        into character;
+        20.0: Height, 10.0: Time => impulse: Impulse;
+    ];
+    assert_eq!(character.impulse, 4.0);
+    assert_eq!(impulse, 1.0);
+}
+
+/// Jump settings with both the impulse and the gravity
+#[derive(Default)]
+struct Settings {
+    impulse: f32,
+    gravity: f32,
+}
+
+/// Read the impulse without naming the field
+fn impulse_of(settings: &Settings) -> f32 {
+    settings.impulse
+}
+
+#[test]
+fn test_assignment_invalidates_shared_inputs() {
+    let mut settings = Settings {
+        impulse: 1.0,
+        ..Default::default()
+    };
+
+    // the second expression is evaluated again once the impulse is assigned
+    jump_parameters![
+        use f32;
+        (impulse_of(&settings) * 2.0): Impulse, 20.0: Height => ga: Gravity;
+        into settings;
+        20.0: Height, 10.0: Time => impulse: Impulse;
+        (impulse_of(&settings) * 2.0): Impulse, 20.0: Height => gb: Gravity;
+    ];
+    assert_eq!(ga, -0.1);
+    assert_eq!(settings.impulse, 4.0);
+    assert_eq!(gb, -1.6);
+}
+
+/// Count the evaluations of the target
+fn counted<'s>(count: &mut u32, settings: &'s mut Settings) -> &'s mut Settings {
+    *count += 1;
+    settings
+}
+
+#[test]
+fn test_assignment_evaluates_target_once() {
+    let mut settings = Settings::default();
+    let mut count = 0;
+
+    jump_parameters![
+        use f32;
+        into counted(&mut count, &mut settings);
+        20.0: Height, 10.0: Time => impulse: Impulse, gravity: Gravity;
+    ];
+    assert_eq!(count, 1);
+    assert_eq!(settings.impulse, 4.0);
+    assert_eq!(settings.gravity, -0.4);
+}
@@ -0,0 +1,19 @@
+use std::process::Command;
+
+/// Build a `#![no_std]` crate depending on arcade_jump without its default features
+#[test]
+fn test_no_std_build() {
+    let status = Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/no_std/Cargo.toml"
+        ))
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/no_std"))
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success());
+}
@@ -0,0 +1,28 @@
+#![no_std]
+
+use arcade_jump::jump_parameter::{float32, float64, jump_parameters};
+
+jump_parameters![
+    use const f32;
+    20.0: Height, 10.0: Time => IMPULSE: Impulse, GRAVITY: Gravity;
+];
+
+pub fn impulse_and_gravity(height: f64, time: f64) -> (f64, f64) {
+    jump_parameters![
+        use f64;
+        height: Height, time: Time => impulse: Impulse, gravity: Gravity;
+    ];
+    (impulse, gravity)
+}
+
+pub fn time_to_peak() -> f32 {
+    float32::time_from_impulse_and_gravity(IMPULSE, GRAVITY)
+}
+
+pub fn times_at_height(impulse: f64, gravity: f64, height: f64) -> Option<(f64, f64)> {
+    float64::times_at_height(impulse, gravity, height)
+}
+
+pub fn impulses(heights: &[f32], times: &[f32], out: &mut [f32]) {
+    float32::impulse_from_height_and_time_slice(heights, times, out);
+}
@@ -0,0 +1,98 @@
+use super::{
+    check_punct,
+    config::FloatType,
+    parameter::{Parameter, ParameterInput, ParameterOutput, ParameterType},
+    select::select_function,
+    ParseTokens, SolveError,
+};
+use proc_macro2::{token_stream::IntoIter, TokenStream};
+use quote::{quote, ToTokens};
+
+/// Two known parameters from which all the parameters are computed
+pub(crate) struct AllParameters {
+    /// First known parameter
+    input1: ParameterInput,
+
+    /// Second known parameter
+    input2: ParameterInput,
+}
+
+impl ParseTokens for AllParameters {
+    /// Parse `ident:ident,ident:ident` from a iterator over tokens
+    fn parse(iter: &mut IntoIter) -> Result<Self, SolveError> {
+        // We expect two inputs in the form:
+        // `my_height: Height, my_time: Time`
+        let input1 = ParameterInput::parse(iter).map_err(SolveError::within_statement)?;
+        let _ = check_punct(iter, ',').map_err(SolveError::within_statement)?;
+        let input2 = ParameterInput::parse(iter).map_err(SolveError::within_statement)?;
+
+        // nothing but an optional `;` can follow
+        match check_punct(iter, ';') {
+            Ok(_) | Err(SolveError::End) => {}
+            Err(error) => return Err(error),
+        }
+        if let Some(token) = iter.next() {
+            return Err(SolveError::Syntax(token, "end of input".to_string()));
+        }
+
+        Ok(Self { input1, input2 })
+    }
+}
+
+impl AllParameters {
+    /// Convert to an expression evaluating to the array `[height, time, impulse, gravity]`
+    pub(crate) fn to_tokens(&self, float_type: &FloatType) -> Result<TokenStream, SolveError> {
+        // pre-evaluate the input variables (if necessary)
+        let in1 = self.input1.pre_evaluate(float_type, 0);
+        let in2 = self.input2.pre_evaluate(float_type, 0);
+        let mut statements = quote![ #in1 #in2 ];
+
+        // the known parameters are used as is, the others are computed
+        let mut values = Vec::new();
+        for parameter_type in ParameterType::VERTICAL {
+            if parameter_type == self.input1.get_type() {
+                values.push(self.input1.get_value(float_type, 0));
+            } else if parameter_type == self.input2.get_type() {
+                values.push(self.input2.get_value(float_type, 0));
+            } else {
+                let name = parameter_type.get_ident(0).into_owned();
+                let output = ParameterOutput::new(name.clone(), parameter_type);
+                statements.extend(select_function(
+                    float_type,
+                    0,
+                    &self.input1,
+                    &self.input2,
+                    &output,
+                )?);
+                values.push(name.into_token_stream());
+            }
+        }
+
+        Ok(quote![{ #statements [ #(#values),* ] }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_parameters() {
+        let float = FloatType::new(false, "f32", "::arcade_jump::jump_parameter::float32");
+        let tokens = quote![ my_time: T, 20.0: H ];
+
+        let all = AllParameters::parse(&mut tokens.into_iter()).unwrap();
+
+        let path = quote![::arcade_jump::jump_parameter::float32];
+        assert_eq!(
+            all.to_tokens(&float).unwrap().to_string(),
+            quote![{
+                let __height0: f32 = 20.0 as f32;
+                let __impulse0: f32 = #path::impulse_from_height_and_time(__height0, my_time);
+                let __gravity0: f32 = #path::gravity_from_height_and_time(__height0, my_time);
+                [__height0, my_time, __impulse0, __gravity0]
+            }]
+            .to_string()
+        );
+    }
+}
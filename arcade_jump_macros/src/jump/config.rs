@@ -1,17 +1,20 @@
-use proc_macro2::{token_stream::IntoIter, TokenStream, TokenTree};
+use proc_macro2::{token_stream::IntoIter, Delimiter, Group, Literal, TokenStream, TokenTree};
 use quote::quote;
 use syn::{parse_str, Path, Type};
 
 use super::{check_punct, check_word, get_word, ParseTokens, SolveError};
 
-/// Specify the float types (f32 or f64) and the module to use
+/// Specify the float types (f16, f32 or f64) and the module to use
 pub(crate) struct FloatType {
     /// Are values evaluated at compile time or runtime
     is_const: bool,
 
-    /// Primitive float type to use
+    /// Float type to use
     float_type: Type,
 
+    /// Is the float type a primitive type that values can be cast to with `as`
+    is_primitive: bool,
+
     /// Path to the module containing the functions
     module_path: Path,
 }
@@ -22,11 +25,12 @@ impl FloatType {
         Self {
             is_const,
             float_type: parse_str(float_type).unwrap(),
+            is_primitive: matches!(float_type, "f32" | "f64"),
             module_path: parse_str(module_path).unwrap(),
         }
     }
 
-    /// Are values evaluated at compile time?
+    /// Are values evaluated at compile time
     #[inline]
     pub(crate) fn is_const(&self) -> bool {
         self.is_const
@@ -42,12 +46,35 @@ impl FloatType {
         }
     }
 
-    /// Primitive float type to use
+    /// Float type to use
     #[inline]
     pub(crate) fn get_float_type(&self) -> &Type {
         &self.float_type
     }
 
+    /// Convert a literal to the float type
+    pub(crate) fn convert_literal(&self, literal: &Literal) -> TokenStream {
+        let float = &self.float_type;
+        if self.is_primitive {
+            quote![ #literal as #float ]
+        } else {
+            quote![ #float::from_f64_const(#literal as f64) ]
+        }
+    }
+
+    /// Convert the result of an expression to the float type,
+    /// expressions must already be of a non-primitive float type
+    pub(crate) fn convert_expr(&self, expr: &Group) -> TokenStream {
+        let float = &self.float_type;
+        if self.is_primitive {
+            quote![ #expr as #float ]
+        } else if expr.delimiter() == Delimiter::Parenthesis {
+            expr.stream()
+        } else {
+            quote![ #expr ]
+        }
+    }
+
     /// Path to the module containing the functions
     #[inline]
     pub(crate) fn get_module_path(&self) -> &Path {
@@ -55,6 +82,9 @@ impl FloatType {
     }
 }
 
+/// The float types supported by the macros
+const FLOAT_TYPES: &str = "a float type: `f16`, `f32` or `f64`";
+
 impl ParseTokens for FloatType {
     /// Read a `use f32;`
     fn parse(iter: &mut IntoIter) -> Result<Self, SolveError> {
@@ -64,10 +94,10 @@ impl ParseTokens for FloatType {
         let _ = check_word(iter, "use")?;
 
         // next token is either `const` or directly the float type
-        let mut word = get_word(iter)?;
+        let mut word = get_word(iter, "`const` or a float type")?;
         let is_const = if word == "const" {
             // next word is the float type
-            word = get_word(iter)?;
+            word = get_word(iter, FLOAT_TYPES)?;
             true
         } else {
             // already the float type
@@ -79,6 +109,11 @@ impl ParseTokens for FloatType {
 
         // evaluate the float type to use
         match word.to_string().as_str() {
+            "f16" => Ok(Self::new(
+                is_const,
+                "::arcade_jump::jump_parameter::float16::f16",
+                "::arcade_jump::jump_parameter::float16",
+            )),
             "f32" => Ok(Self::new(
                 is_const,
                 "f32",
@@ -89,7 +124,12 @@ impl ParseTokens for FloatType {
                 "f64",
                 "::arcade_jump::jump_parameter::float64",
             )),
-            _ => Err(SolveError::Syntax(TokenTree::Ident(word))),
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+            | "u128" | "usize" => Err(SolveError::IntegerType(word)),
+            _ => Err(SolveError::Syntax(
+                TokenTree::Ident(word),
+                FLOAT_TYPES.to_string(),
+            )),
         }
     }
 }
@@ -1,3 +1,6 @@
+/// Compute all the parameters from two of them
+mod all;
+
 /// Which floating type number to use
 mod config;
 
@@ -10,9 +13,10 @@ mod select;
 /// How to read a statement
 mod statement;
 
+use all::AllParameters;
 use config::FloatType;
-use parameter::{ParameterInput, ParameterOutput};
-use proc_macro2::{token_stream::IntoIter, Ident, Punct, TokenStream, TokenTree};
+use parameter::{EvaluatedInputs, Parameter, ParameterInput, ParameterOutput};
+use proc_macro2::{token_stream::IntoIter, Ident, Punct, Span, TokenStream, TokenTree};
 use statement::Statement;
 
 /// Parse token stream and generate instruction to compute variables
@@ -41,6 +45,14 @@ pub(crate) fn generate_calculator(tokens: TokenStream) -> Result<TokenStream, So
         }
     }
 
+    // Evaluate identical input expressions only once, constants are evaluated in place
+    if !float.is_const() {
+        let mut evaluated = EvaluatedInputs::default();
+        for (index, stmt) in statements.iter_mut().enumerate() {
+            stmt.share_inputs(&mut evaluated, index);
+        }
+    }
+
     // Generate the statements
     let mut output = TokenStream::new();
     for (index, stmt) in statements.iter().enumerate() {
@@ -50,6 +62,17 @@ pub(crate) fn generate_calculator(tokens: TokenStream) -> Result<TokenStream, So
     Ok(output)
 }
 
+/// Parse token stream and generate an expression evaluating to all the parameters
+pub(crate) fn generate_all(tokens: TokenStream) -> Result<TokenStream, SolveError> {
+    let mut iter = tokens.into_iter();
+
+    // The first statement is the float type to use
+    let float = FloatType::parse(&mut iter)?;
+
+    // Then the two known parameters
+    AllParameters::parse(&mut iter)?.to_tokens(&float)
+}
+
 /// The type of errors encountered when parsing statements
 #[repr(u32)]
 #[derive(Debug)]
@@ -57,28 +80,100 @@ pub(crate) enum SolveError {
     /// End of the stream of tokens
     End,
 
-    /// Error in the syntax
-    Syntax(TokenTree),
+    /// End of the stream of tokens in the middle of a statement
+    Incomplete,
+
+    /// Error in the syntax, with a description of what was expected instead
+    Syntax(TokenTree, String),
+
+    /// Integer type given instead of a float type
+    IntegerType(Ident),
+
+    /// Assignment to a struct while evaluating constants
+    ConstAssignment(TokenStream),
 
     /// Error on the sequence of parameters
     Parameter {
-        input1: ParameterInput,
-        input2: ParameterInput,
-        output: ParameterOutput,
+        input1: Box<ParameterInput>,
+        input2: Box<ParameterInput>,
+        output: Box<ParameterOutput>,
     },
 }
 
+impl SolveError {
+    /// Reaching the end of the stream of tokens within a statement is an error
+    pub(crate) fn within_statement(self) -> Self {
+        match self {
+            Self::End => Self::Incomplete,
+            error => error,
+        }
+    }
+}
+
+/// Report the error as a compiler diagnostic
+impl From<SolveError> for syn::Error {
+    fn from(error: SolveError) -> Self {
+        match error {
+            SolveError::End => Self::new(Span::call_site(), "unexpected end of input"),
+            SolveError::Incomplete => Self::new(
+                Span::call_site(),
+                "unexpected end of input, the last statement is incomplete",
+            ),
+            SolveError::Syntax(token, expected) => {
+                Self::new_spanned(token, format!["unexpected token, expected {}", expected])
+            }
+            SolveError::IntegerType(word) => Self::new_spanned(
+                &word,
+                format![
+                    "`{}` is not supported, the jump parameters involve square roots \
+                     and divisions which require a float type: `f16`, `f32` or `f64`",
+                    word
+                ],
+            ),
+            SolveError::ConstAssignment(target) => Self::new_spanned(
+                target,
+                "cannot assign the outputs to a struct with `use const`",
+            ),
+            SolveError::Parameter {
+                input1,
+                input2,
+                output,
+            } => {
+                let (type1, type2, out) = (input1.get_type(), input2.get_type(), output.get_type());
+
+                // list the pairs of inputs that would have been accepted
+                let pairs = select::valid_inputs(out)
+                    .iter()
+                    .map(|(valid1, valid2)| format!["`{}` and `{}`", valid1, valid2])
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let message = format![
+                    "cannot compute `{}` from `{}` and `{}`, expected one of: {}",
+                    out, type1, type2, pairs
+                ];
+
+                // blame the output if it is already known, otherwise the second input
+                if out == type1 || out == type2 {
+                    Self::new_spanned(output, message)
+                } else {
+                    Self::new_spanned(input2, message)
+                }
+            }
+        }
+    }
+}
+
 /// Read a sequence of tokens to get the expected type
 pub(crate) trait ParseTokens: Sized {
     fn parse(iter: &mut IntoIter) -> Result<Self, SolveError>;
 }
 
 /// Get the next token and expect it to be a punctuation
-fn get_punct(iter: &mut IntoIter) -> Result<Punct, SolveError> {
+fn get_punct(iter: &mut IntoIter, expected: &str) -> Result<Punct, SolveError> {
     if let Some(token) = iter.next() {
         match token {
             TokenTree::Punct(punct) => Ok(punct),
-            _ => Err(SolveError::Syntax(token)),
+            _ => Err(SolveError::Syntax(token, expected.to_string())),
         }
     } else {
         Err(SolveError::End)
@@ -87,20 +182,21 @@ fn get_punct(iter: &mut IntoIter) -> Result<Punct, SolveError> {
 
 /// Check if the next token is the specified punctuation
 fn check_punct(iter: &mut IntoIter, expect: char) -> Result<Punct, SolveError> {
-    let punct = get_punct(iter)?;
+    let expected = format!["`{}`", expect];
+    let punct = get_punct(iter, &expected)?;
     if punct.as_char() != expect {
-        Err(SolveError::Syntax(TokenTree::Punct(punct)))
+        Err(SolveError::Syntax(TokenTree::Punct(punct), expected))
     } else {
         Ok(punct)
     }
 }
 
 /// Get the next token and expect it to be a word
-fn get_word(iter: &mut IntoIter) -> Result<Ident, SolveError> {
+fn get_word(iter: &mut IntoIter, expected: &str) -> Result<Ident, SolveError> {
     if let Some(token) = iter.next() {
         match token {
             TokenTree::Ident(word) => Ok(word),
-            _ => Err(SolveError::Syntax(token)),
+            _ => Err(SolveError::Syntax(token, expected.to_string())),
         }
     } else {
         Err(SolveError::End)
@@ -109,10 +205,92 @@ fn get_word(iter: &mut IntoIter) -> Result<Ident, SolveError> {
 
 /// Check if the next token is the specified word
 fn check_word(iter: &mut IntoIter, expect: &str) -> Result<Ident, SolveError> {
-    let word = get_word(iter)?;
+    let expected = format!["`{}`", expect];
+    let word = get_word(iter, &expected)?;
     if word != expect {
-        Err(SolveError::Syntax(TokenTree::Ident(word)))
+        Err(SolveError::Syntax(TokenTree::Ident(word), expected))
     } else {
         Ok(word)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_shared_expression() {
+        let tokens = quote![
+            use f32;
+            my_height: H, (my_impulse * 2.0): I => my_gravity: G;
+            my_time: T, (my_impulse * 2.0): I => my_other_gravity: G;
+        ];
+        let output = generate_calculator(tokens).unwrap().to_string();
+
+        let expr = quote![(my_impulse * 2.0)].to_string();
+        assert_eq!(output.matches(&expr).count(), 1);
+        assert_eq!(output.matches("__impulse0").count(), 3);
+    }
+
+    #[test]
+    fn test_statement_separator() {
+        let one = quote![
+            use f32;
+            my_height: H, my_time: T => my_impulse: I
+        ];
+        let two = quote![
+            use f32;
+            my_height: H, my_time: T => my_impulse: I;
+            my_height: H, my_time: T => my_gravity: G
+        ];
+        let trailing = quote![
+            use f32;
+            my_height: H, my_time: T => my_impulse: I;
+            my_height: H, my_time: T => my_gravity: G;
+        ];
+
+        let one = generate_calculator(one).unwrap().to_string();
+        assert_eq!(one.matches("let").count(), 1);
+
+        let two = generate_calculator(two).unwrap().to_string();
+        assert_eq!(two.matches("let").count(), 2);
+
+        let trailing = generate_calculator(trailing).unwrap().to_string();
+        assert_eq!(trailing, two);
+    }
+
+    #[test]
+    fn test_assignment() {
+        let tokens = quote![
+            use f32;
+            into self.player;
+            my_height: H, my_time: T => impulse: I, gravity: G;
+            into: H, my_impulse: I => time: T;
+        ];
+        let output = generate_calculator(tokens).unwrap().to_string();
+
+        let path = quote![::arcade_jump::jump_parameter::float32];
+        assert_eq!(
+            output,
+            quote![
+                let __target0 = &mut (self.player);
+                __target0.impulse = #path::impulse_from_height_and_time(my_height, my_time);
+                __target0.gravity = #path::gravity_from_height_and_time(my_height, my_time);
+                let time: f32 = #path::time_from_height_and_impulse(into, my_impulse);
+            ]
+            .to_string()
+        );
+
+        // constants cannot be assigned to a struct
+        let tokens = quote![
+            use const f32;
+            into PLAYER;
+            HEIGHT: H, TIME: T => IMPULSE: I;
+        ];
+        assert!(matches!(
+            generate_calculator(tokens),
+            Err(SolveError::ConstAssignment(_))
+        ));
+    }
+}
@@ -1,7 +1,7 @@
-use super::{check_punct, config::FloatType, ParseTokens, SolveError};
+use super::{check_punct, config::FloatType, get_word, ParseTokens, SolveError};
 use proc_macro2::{token_stream::IntoIter, Group, Ident, Literal, Span, TokenStream, TokenTree};
-use quote::quote;
-use std::borrow::Cow;
+use quote::{quote, ToTokens};
+use std::{borrow::Cow, collections::HashMap, fmt};
 
 /// Parameter trait
 pub(crate) trait Parameter {
@@ -23,6 +23,9 @@ pub(crate) struct ParameterInput {
 
     /// Type of the parameter
     parameter_type: ParameterType,
+
+    /// Name given to the type of the parameter
+    type_name: Ident,
 }
 
 /// Either an identifier, a literal or an expression
@@ -46,11 +49,21 @@ pub(crate) struct ParameterOutput {
 
     /// Type of the parameter
     parameter_type: ParameterType,
+
+    /// Name given to the type of the parameter
+    type_name: Ident,
+}
+
+/// Input expressions already bound to a variable by a previous statement
+#[derive(Default)]
+pub(crate) struct EvaluatedInputs {
+    /// Tokens of each expression and the variable holding its value, keyed by the expression
+    bindings: HashMap<String, (TokenStream, Ident)>,
 }
 
 /// Parameter type
 #[repr(u32)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ParameterType {
     /// Peak height
     Height = 0,
@@ -63,6 +76,50 @@ pub(crate) enum ParameterType {
 
     /// Gravity force
     Gravity = 3,
+
+    /// Horizontal speed
+    Speed = 4,
+
+    /// Horizontal distance covered by the jump
+    Range = 5,
+}
+
+impl ParameterType {
+    /// All the parameter types in canonical order
+    pub(crate) const ALL: [Self; 6] = [
+        Self::Height,
+        Self::Time,
+        Self::Impulse,
+        Self::Gravity,
+        Self::Speed,
+        Self::Range,
+    ];
+
+    /// The parameters describing the vertical motion, all of them can be deduced from two others
+    pub(crate) const VERTICAL: [Self; 4] = [Self::Height, Self::Time, Self::Impulse, Self::Gravity];
+
+    /// Single letter name of the parameter
+    #[rustfmt::skip]
+    pub(crate) fn short_name(&self) -> &'static str {
+        match self {
+            Self::Height  => "H",
+            Self::Time    => "T",
+            Self::Impulse => "I",
+            Self::Gravity => "G",
+            Self::Speed   => "S",
+            Self::Range   => "R",
+        }
+    }
+}
+
+/// Describe the names accepted for the parameter types
+fn expected_parameter_types() -> String {
+    let names = ParameterType::ALL
+        .iter()
+        .map(|param| format!["`{}` (`{}`)", param, param.short_name()])
+        .collect::<Vec<_>>();
+    let (last, first) = names.split_last().unwrap();
+    format!["a parameter type: {} or {}", first.join(", "), last]
 }
 
 impl ParseTokens for ParameterInput {
@@ -76,10 +133,12 @@ impl ParseTokens for ParameterInput {
         if let Some(token) = iter.next() {
             let variable_input = VariableInput::try_from(token)?;
             let _ = check_punct(iter, ':')?;
-            let parameter_type = ParameterType::parse(iter)?;
+            let type_name = get_word(iter, &expected_parameter_types())?;
+            let parameter_type = ParameterType::try_from(&type_name)?;
             Ok(Self {
                 variable_input,
                 parameter_type,
+                type_name,
             })
         } else {
             Err(SolveError::End)
@@ -96,28 +155,15 @@ impl ParseTokens for ParameterOutput {
         if let Some(token) = iter.next() {
             if let TokenTree::Ident(variable_name) = token {
                 let _ = check_punct(iter, ':')?;
-                let parameter_type = ParameterType::parse(iter)?;
+                let type_name = get_word(iter, &expected_parameter_types())?;
+                let parameter_type = ParameterType::try_from(&type_name)?;
                 Ok(Self {
                     variable_name,
                     parameter_type,
+                    type_name,
                 })
             } else {
-                Err(SolveError::Syntax(token))
-            }
-        } else {
-            Err(SolveError::End)
-        }
-    }
-}
-
-impl ParseTokens for ParameterType {
-    /// Parse an identifier from the token stream to deduce the parameter type
-    fn parse(iter: &mut IntoIter) -> Result<Self, SolveError> {
-        if let Some(token) = iter.next() {
-            if let TokenTree::Ident(name) = token {
-                Self::try_from(name.to_string().as_str())
-            } else {
-                Err(SolveError::Syntax(token))
+                Err(SolveError::Syntax(token, "a variable name".to_string()))
             }
         } else {
             Err(SolveError::End)
@@ -134,7 +180,10 @@ impl TryFrom<TokenTree> for VariableInput {
             TokenTree::Literal(literal) => Ok(Self::Literal(literal)),
             TokenTree::Ident(ident) => Ok(Self::Ident(ident)),
             TokenTree::Group(group) => Ok(Self::Expr(group)),
-            _ => Err(SolveError::Syntax(token)),
+            _ => Err(SolveError::Syntax(
+                token,
+                "a variable, a literal or a parenthesized expression".to_string(),
+            )),
         }
     }
 }
@@ -146,38 +195,164 @@ impl TryFrom<&str> for ParameterType {
     #[rustfmt::skip]
     fn try_from(name: &str) -> Result<Self, SolveError> {
         match name {
-            "H" | "Height"  => Ok(Self::Height ),
-            "T" | "Time"    => Ok(Self::Time   ),
-            "I" | "Impulse" => Ok(Self::Impulse),
-            "G" | "Gravity" => Ok(Self::Gravity),
-            _ => Err(SolveError::Syntax(TokenTree::Ident(Ident::new(
-                name,
-                Span::call_site(),
-            )))),
+            "H" | "Height"        => Ok(Self::Height ),
+            "T" | "Time"          => Ok(Self::Time   ),
+            "I" | "Impulse"       => Ok(Self::Impulse),
+            "G" | "Gravity"       => Ok(Self::Gravity),
+            "S" | "Speed"         => Ok(Self::Speed  ),
+            "R" | "D" | "Range"   => Ok(Self::Range  ),
+            _ => Err(SolveError::Syntax(
+                TokenTree::Ident(Ident::new(name, Span::call_site())),
+                expected_parameter_types(),
+            )),
+        }
+    }
+}
+
+/// Identify the parameter while keeping track of where it was written
+impl TryFrom<&Ident> for ParameterType {
+    type Error = SolveError;
+
+    fn try_from(name: &Ident) -> Result<Self, SolveError> {
+        Self::try_from(name.to_string().as_str()).map_err(|error| match error {
+            SolveError::Syntax(_, expected) => {
+                SolveError::Syntax(TokenTree::Ident(name.clone()), expected)
+            }
+            error => error,
+        })
+    }
+}
+
+/// Display the full name of the parameter
+impl fmt::Display for ParameterType {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Height  => "Height" ,
+            Self::Time    => "Time"   ,
+            Self::Impulse => "Impulse",
+            Self::Gravity => "Gravity",
+            Self::Speed   => "Speed"  ,
+            Self::Range   => "Range"  ,
+        })
+    }
+}
+
+/// Give back the tokens of the input parameter as it was written
+impl ToTokens for ParameterInput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.variable_input {
+            VariableInput::Ident(ident) => ident.to_tokens(tokens),
+            VariableInput::Literal(literal) => literal.to_tokens(tokens),
+            VariableInput::Expr(expr) => expr.to_tokens(tokens),
         }
+        let type_name = &self.type_name;
+        tokens.extend(quote![ : #type_name ]);
+    }
+}
+
+/// Give back the tokens of the output parameter as it was written
+impl ToTokens for ParameterOutput {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variable_name = &self.variable_name;
+        let type_name = &self.type_name;
+        tokens.extend(quote![ #variable_name : #type_name ]);
     }
 }
 
 impl ParameterInput {
-    /// Preevaluate input expressions once
+    /// Preevaluate input expressions once,
+    /// constants are evaluated in place since a temporary item would leak out of the macro
     pub(crate) fn pre_evaluate(&self, float_type: &FloatType, index: usize) -> TokenStream {
-        let let_const = float_type.let_const_token();
+        if float_type.is_const() {
+            return TokenStream::new();
+        }
         let float = float_type.get_float_type();
 
         match &self.variable_input {
             VariableInput::Ident(_) => TokenStream::new(),
             VariableInput::Literal(literal) => {
                 let param = self.get_ident(index).into_owned();
-                quote![ #let_const #param : #float = #literal as #float; ]
+                let value = float_type.convert_literal(literal);
+                quote![ let #param : #float = #value; ]
             }
             VariableInput::Expr(expr) => {
                 let param = self.get_ident(index).into_owned();
-                quote![ #let_const #param : #float = #expr as #float; ]
+                let value = float_type.convert_expr(expr);
+                quote![ let #param : #float = #value; ]
+            }
+        }
+    }
+
+    /// Get the tokens evaluating to the value of this parameter
+    pub(crate) fn get_value(&self, float_type: &FloatType, index: usize) -> TokenStream {
+        match &self.variable_input {
+            VariableInput::Literal(literal) if float_type.is_const() => {
+                float_type.convert_literal(literal)
             }
+            VariableInput::Expr(expr) if float_type.is_const() => float_type.convert_expr(expr),
+            _ => self.get_ident(index).into_token_stream(),
         }
     }
 }
 
+impl ParameterOutput {
+    /// Create an output parameter stored in a variable of the given name
+    pub(crate) fn new(variable_name: Ident, parameter_type: ParameterType) -> Self {
+        let type_name = Ident::new(&parameter_type.to_string(), Span::call_site());
+        Self {
+            variable_name,
+            parameter_type,
+            type_name,
+        }
+    }
+}
+
+impl EvaluatedInputs {
+    /// Reuse the variable bound to an identical expression or remember this one
+    pub(crate) fn share(&mut self, input: &mut ParameterInput, index: usize) {
+        let tokens = match &input.variable_input {
+            VariableInput::Ident(_) => return,
+            VariableInput::Literal(literal) => literal.to_token_stream(),
+            VariableInput::Expr(expr) => expr.to_token_stream(),
+        };
+
+        let key = tokens.to_string();
+        if let Some((_, ident)) = self.bindings.get(&key) {
+            input.variable_input = VariableInput::Ident(ident.clone());
+        } else {
+            let ident = input.get_ident(index).into_owned();
+            self.bindings.insert(key, (tokens, ident));
+        }
+    }
+
+    /// Forget the expressions referring to a variable which is being redefined
+    pub(crate) fn invalidate(&mut self, name: &Ident) {
+        self.bindings
+            .retain(|_, (tokens, _)| !mentions(tokens.clone(), name));
+    }
+
+    /// Forget the expressions referring to any variable appearing in an expression being modified
+    pub(crate) fn invalidate_all(&mut self, modified: &TokenStream) {
+        for token in modified.clone() {
+            match token {
+                TokenTree::Ident(ident) => self.invalidate(&ident),
+                TokenTree::Group(group) => self.invalidate_all(&group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Check if an identifier appears anywhere in a stream of tokens
+fn mentions(tokens: TokenStream, name: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == *name,
+        TokenTree::Group(group) => mentions(group.stream(), name),
+        _ => false,
+    })
+}
+
 impl Parameter for ParameterType {
     /// Get the identifier for this parameter
     #[rustfmt::skip]
@@ -187,8 +362,11 @@ impl Parameter for ParameterType {
             Self::Time    => "time"   ,
             Self::Impulse => "impulse",
             Self::Gravity => "gravity",
+            Self::Speed   => "speed"  ,
+            Self::Range   => "range"  ,
         };
-        Cow::Owned(Ident::new(&format!["__{}{}", name, index], Span::call_site()))
+        // temporaries cannot be named from the code surrounding the macro
+        Cow::Owned(Ident::new(&format!["__{}{}", name, index], Span::mixed_site()))
     }
 
     /// Get the type of this parameter
@@ -272,4 +450,50 @@ mod tests {
         assert_eq!(my_impulse.get_ident(0).as_ref(), "my_impulse");
         assert_eq!(my_gravity.get_ident(0).as_ref(), "my_gravity");
     }
+
+    #[test]
+    fn test_parameter_names() {
+        for param in ParameterType::ALL {
+            assert_eq!(
+                ParameterType::try_from(param.short_name()).ok(),
+                Some(param)
+            );
+            assert_eq!(
+                ParameterType::try_from(param.to_string().as_str()).ok(),
+                Some(param)
+            );
+        }
+
+        // alternative name of the range
+        assert_eq!(
+            ParameterType::try_from("D").ok(),
+            Some(ParameterType::Range)
+        );
+    }
+
+    #[test]
+    fn test_share_input() {
+        let tokens1 = quote![ (my_height * 2.0) : Height ];
+        let tokens2 = quote![ (my_height * 2.0) : H ];
+        let tokens3 = quote![ (my_height * 3.0) : H ];
+
+        let mut first = ParameterInput::parse(&mut tokens1.into_iter()).unwrap();
+        let mut second = ParameterInput::parse(&mut tokens2.into_iter()).unwrap();
+        let mut third = ParameterInput::parse(&mut tokens3.into_iter()).unwrap();
+
+        let mut evaluated = EvaluatedInputs::default();
+        evaluated.share(&mut first, 0);
+        evaluated.share(&mut second, 1);
+        evaluated.share(&mut third, 2);
+        assert_eq!(first.get_ident(0).as_ref(), "__height0");
+        assert_eq!(second.get_ident(1).as_ref(), "__height0");
+        assert_eq!(third.get_ident(2).as_ref(), "__height2");
+
+        // redefining `my_height` makes the expression evaluate to something else
+        let tokens4 = quote![ (my_height * 2.0) : H ];
+        let mut fourth = ParameterInput::parse(&mut tokens4.into_iter()).unwrap();
+        evaluated.invalidate(&Ident::new("my_height", Span::call_site()));
+        evaluated.share(&mut fourth, 3);
+        assert_eq!(fourth.get_ident(3).as_ref(), "__height3");
+    }
 }
@@ -15,45 +15,84 @@ pub fn select_function(
     param2: &ParameterInput,
     output: &ParameterOutput,
 ) -> Result<TokenStream, SolveError> {
-    // find the appropriate function to call
-    type Type = ParameterType;
+    let call = function_call(float_type, index, param1, param2, output)?;
+
+    // prepare the tokens
+    let eval = float_type.let_const_token();
+    let result = output.get_ident(index).into_owned();
+    let float = float_type.get_float_type();
 
+    // generate the statement
+    Ok(quote![#eval #result: #float = #call;])
+}
+
+/// Call to the function computing this parameter type from the two other parameters
+pub fn function_call(
+    float_type: &FloatType,
+    index: usize,
+    param1: &ParameterInput,
+    param2: &ParameterInput,
+    output: &ParameterOutput,
+) -> Result<TokenStream, SolveError> {
     // reorder the parameters as: Height, Time, Impulse, Gravity
     let (ord1, ord2) = param1.reorder(param2);
 
     // figure out if the combination of parameter is valid
-    #[rustfmt::skip]
-    let func_name = match (ord1.get_type(), ord2.get_type(), output.get_type()) {
-        (Type::Height , Type::Time   , Type::Impulse) => Ok("impulse_from_height_and_time"   ),
-        (Type::Height , Type::Time   , Type::Gravity) => Ok("gravity_from_height_and_time"   ),
-        (Type::Height , Type::Impulse, Type::Time   ) => Ok("time_from_height_and_impulse"   ),
-        (Type::Height , Type::Impulse, Type::Gravity) => Ok("gravity_from_height_and_impulse"),
-        (Type::Height , Type::Gravity, Type::Time   ) => Ok("time_from_height_and_gravity"   ),
-        (Type::Height , Type::Gravity, Type::Impulse) => Ok("impulse_from_height_and_gravity"),
-        (Type::Time   , Type::Impulse, Type::Height ) => Ok("height_from_time_and_impulse"   ),
-        (Type::Time   , Type::Impulse, Type::Gravity) => Ok("gravity_from_time_and_impulse"  ),
-        (Type::Time   , Type::Gravity, Type::Height ) => Ok("height_from_time_and_gravity"   ),
-        (Type::Time   , Type::Gravity, Type::Impulse) => Ok("impulse_from_time_and_gravity"  ),
-        (Type::Impulse, Type::Gravity, Type::Height ) => Ok("height_from_impulse_and_gravity"),
-        (Type::Impulse, Type::Gravity, Type::Time   ) => Ok("time_from_impulse_and_gravity"  ),
-        _ => Err(SolveError::Parameter {
-            input1: param1.clone(),
-            input2: param2.clone(),
-            output: output.clone(),
-        }),
-    }?;
+    let func_name =
+        function_name(ord1.get_type(), ord2.get_type(), output.get_type()).ok_or_else(|| {
+            SolveError::Parameter {
+                input1: Box::new(param1.clone()),
+                input2: Box::new(param2.clone()),
+                output: Box::new(output.clone()),
+            }
+        })?;
 
     // prepare the tokens
-    let eval = float_type.let_const_token();
-    let result = output.get_ident(index).into_owned();
-    let float = float_type.get_float_type();
     let path = float_type.get_module_path();
     let func = Ident::new(func_name, Span::call_site());
-    let var1 = ord1.get_ident(index).into_owned();
-    let var2 = ord2.get_ident(index).into_owned();
+    let var1 = ord1.get_value(float_type, index);
+    let var2 = ord2.get_value(float_type, index);
 
-    // generate the statement
-    Ok(quote![#eval #result: #float = #path::#func(#var1, #var2);])
+    Ok(quote![#path::#func(#var1, #var2)])
+}
+
+/// Name of the function computing the output from two ordered input parameters
+#[rustfmt::skip]
+pub(crate) fn function_name(
+    input1: ParameterType,
+    input2: ParameterType,
+    output: ParameterType,
+) -> Option<&'static str> {
+    type Type = ParameterType;
+    match (input1, input2, output) {
+        (Type::Height , Type::Time   , Type::Impulse) => Some("impulse_from_height_and_time"   ),
+        (Type::Height , Type::Time   , Type::Gravity) => Some("gravity_from_height_and_time"   ),
+        (Type::Height , Type::Impulse, Type::Time   ) => Some("time_from_height_and_impulse"   ),
+        (Type::Height , Type::Impulse, Type::Gravity) => Some("gravity_from_height_and_impulse"),
+        (Type::Height , Type::Gravity, Type::Time   ) => Some("time_from_height_and_gravity"   ),
+        (Type::Height , Type::Gravity, Type::Impulse) => Some("impulse_from_height_and_gravity"),
+        (Type::Time   , Type::Impulse, Type::Height ) => Some("height_from_time_and_impulse"   ),
+        (Type::Time   , Type::Impulse, Type::Gravity) => Some("gravity_from_time_and_impulse"  ),
+        (Type::Time   , Type::Gravity, Type::Height ) => Some("height_from_time_and_gravity"   ),
+        (Type::Time   , Type::Gravity, Type::Impulse) => Some("impulse_from_time_and_gravity"  ),
+        (Type::Impulse, Type::Gravity, Type::Height ) => Some("height_from_impulse_and_gravity"),
+        (Type::Impulse, Type::Gravity, Type::Time   ) => Some("time_from_impulse_and_gravity"  ),
+        (Type::Speed  , Type::Range  , Type::Time   ) => Some("time_from_speed_and_range"      ),
+        _ => None,
+    }
+}
+
+/// List the ordered pairs of input parameters from which the output can be computed
+pub(crate) fn valid_inputs(output: ParameterType) -> Vec<(ParameterType, ParameterType)> {
+    let mut pairs = Vec::new();
+    for (index, &input1) in ParameterType::ALL.iter().enumerate() {
+        for &input2 in &ParameterType::ALL[index + 1..] {
+            if function_name(input1, input2, output).is_some() {
+                pairs.push((input1, input2));
+            }
+        }
+    }
+    pairs
 }
 
 #[cfg(test)]
@@ -62,6 +101,68 @@ mod tests {
     use crate::jump::parameter::*;
     use crate::jump::*;
 
+    #[test]
+    fn test_valid_inputs() {
+        type Type = ParameterType;
+        assert_eq!(valid_inputs(Type::Speed), vec![]);
+        assert_eq!(
+            valid_inputs(Type::Impulse),
+            vec![
+                (Type::Height, Type::Time),
+                (Type::Height, Type::Gravity),
+                (Type::Time, Type::Gravity),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_function_table() {
+        let position = |param| ParameterType::ALL.iter().position(|&p| p == param).unwrap();
+        let float = FloatType::new(false, "f32", "::arcade_jump::jump_parameter::float32");
+        let path = quote![::arcade_jump::jump_parameter::float32];
+        let input = |name: &str, param: ParameterType| {
+            let name = Ident::new(name, Span::call_site());
+            let param = Ident::new(&param.to_string(), Span::call_site());
+            ParameterInput::parse(&mut quote![ #name : #param ].into_iter()).unwrap()
+        };
+
+        let mut count = 0;
+        for input1 in ParameterType::ALL {
+            for input2 in ParameterType::ALL {
+                for output in ParameterType::ALL {
+                    let Some(name) = function_name(input1, input2, output) else {
+                        continue;
+                    };
+
+                    // the inputs are ordered and the output is not one of them
+                    assert!(position(input1) < position(input2), "{name}");
+                    assert!(output != input1 && output != input2, "{name}");
+
+                    // the name of the function follows the order of the parameters
+                    let expected = format!["{output}_from_{input1}_and_{input2}"].to_lowercase();
+                    assert_eq!(name, expected);
+
+                    // the function is selected from the inputs in order
+                    let output = ParameterOutput::new(Ident::new("out", Span::call_site()), output);
+                    let (first, second) = (input("first", input1), input("second", input2));
+                    let func = Ident::new(name, Span::call_site());
+                    let expected = quote![let out: f32 = #path::#func(first, second);].to_string();
+                    let tokens = select_function(&float, 0, &first, &second, &output).unwrap();
+                    assert_eq!(tokens.to_string(), expected);
+                    count += 1;
+
+                    // the reversed pair is reordered before the function is selected
+                    let tokens = select_function(&float, 0, &second, &first, &output).unwrap();
+                    assert_eq!(tokens.to_string(), expected);
+                    count += 1;
+                }
+            }
+        }
+
+        // a new combination must be accounted for here
+        assert_eq!(count, 26);
+    }
+
     #[test]
     fn test_func_select() {
         let float = FloatType::new(false, "f32", "::arcade_jump::jump_parameter::float32");
@@ -83,4 +184,27 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn test_range_select() {
+        let float = FloatType::new(false, "f32", "::arcade_jump::jump_parameter::float32");
+        let tokens1 = quote![ my_range : R    ];
+        let tokens2 = quote![ my_speed : S    ];
+        let tokens3 = quote![ my_time  : Time ];
+
+        let my_range = ParameterInput::parse(&mut tokens1.into_iter()).unwrap();
+        let my_speed = ParameterInput::parse(&mut tokens2.into_iter()).unwrap();
+        let my_time = ParameterOutput::parse(&mut tokens3.into_iter()).unwrap();
+
+        // the speed comes first whatever the order the inputs are written in
+        let path = quote![::arcade_jump::jump_parameter::float32];
+        let expected = quote![
+            let my_time: f32 = #path::time_from_speed_and_range(my_speed, my_range);
+        ]
+        .to_string();
+        for (input1, input2) in [(&my_range, &my_speed), (&my_speed, &my_range)] {
+            let tokens = select_function(&float, 0, input1, input2, &my_time).unwrap();
+            assert_eq!(tokens.to_string(), expected);
+        }
+    }
 }
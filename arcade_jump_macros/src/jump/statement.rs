@@ -2,11 +2,11 @@ use super::{
     check_punct,
     config::FloatType,
     get_punct,
-    parameter::{ParameterInput, ParameterOutput},
-    select::select_function,
+    parameter::{EvaluatedInputs, Parameter, ParameterInput, ParameterOutput},
+    select::{function_call, select_function},
     ParseTokens, SolveError,
 };
-use proc_macro2::{token_stream::IntoIter, Spacing, TokenStream, TokenTree};
+use proc_macro2::{token_stream::IntoIter, Ident, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
 
 /// A statement taking two parameters and resulting into one or two other parameters
@@ -22,6 +22,9 @@ pub(crate) struct Statement {
 
     /// Optional second output parameter
     output2: Option<ParameterOutput>,
+
+    /// Optional struct whose fields are assigned the outputs
+    target: Option<TokenStream>,
 }
 
 impl ParseTokens for Statement {
@@ -30,33 +33,96 @@ impl ParseTokens for Statement {
         // We expect statements in the form:
         // `my_height: Height, my_time: Time => my_impulse: Impulse;`
         // `my_height: H, my_time: T => my_impulse: I, my_gravity: G;`
+        // `into my_struct; my_height: H, my_time: T => impulse: I;`
+
+        // Read two inputs, the stream of tokens may only end before the first one
+        let target = Self::parse_target(iter)?;
+        let input1 = match ParameterInput::parse(iter) {
+            Err(SolveError::End) if target.is_some() => return Err(SolveError::Incomplete),
+            result => result?,
+        };
+        Self::parse_rest(iter, input1, target).map_err(SolveError::within_statement)
+    }
+}
 
-        // Read two inputs
-        let input1 = ParameterInput::parse(iter)?;
+impl Statement {
+    /// Parse the optional `into <expr>;` prefix of a statement
+    fn parse_target(iter: &mut IntoIter) -> Result<Option<TokenStream>, SolveError> {
+        // `into` may also be the name of an input followed by a `:`
+        let mut lookahead = iter.clone();
+        match (lookahead.next(), lookahead.next()) {
+            (Some(TokenTree::Ident(word)), Some(TokenTree::Punct(punct)))
+                if word == "into" && punct.as_char() == ':' =>
+            {
+                return Ok(None)
+            }
+            (Some(TokenTree::Ident(word)), Some(_)) if word == "into" => {}
+            _ => return Ok(None),
+        }
+        let _ = iter.next();
+
+        // the expression to assign to ends with a `;`
+        let mut target = TokenStream::new();
+        loop {
+            match iter.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => {
+                    if target.is_empty() {
+                        return Err(SolveError::Syntax(
+                            TokenTree::Punct(punct),
+                            "an expression to assign to".to_string(),
+                        ));
+                    }
+                    return Ok(Some(target));
+                }
+                Some(token) => target.extend([token]),
+                None => return Err(SolveError::Incomplete),
+            }
+        }
+    }
+
+    /// Parse the rest of a statement once its first input has been read
+    fn parse_rest(
+        iter: &mut IntoIter,
+        input1: ParameterInput,
+        target: Option<TokenStream>,
+    ) -> Result<Self, SolveError> {
         let _ = check_punct(iter, ',')?;
         let input2 = ParameterInput::parse(iter)?;
 
         // verify that the two parts are separated by a "=>"
-        let arrow = check_punct(iter, '=')?;
-        if arrow.spacing() == Spacing::Joint {
+        let arrow = get_punct(iter, "`=>`")?;
+        if arrow.as_char() == '=' && arrow.spacing() == Spacing::Joint {
             let _ = check_punct(iter, '>')?;
         } else {
-            return Err(SolveError::Syntax(TokenTree::Punct(arrow)));
+            return Err(SolveError::Syntax(
+                TokenTree::Punct(arrow),
+                "`=>`".to_string(),
+            ));
         }
 
         // Read a first output
         let output1 = ParameterOutput::parse(iter)?;
 
-        // either there is a second output or we stop there
-        let punct = get_punct(iter)?;
-        let output2 = match punct.as_char() {
-            ',' => {
+        // either there is a second output or we stop there,
+        // the `;` is optional after the last statement
+        let output2 = match get_punct(iter, "`,` or `;`") {
+            Ok(punct) if punct.as_char() == ',' => {
                 let output = ParameterOutput::parse(iter)?;
-                let _ = check_punct(iter, ';')?;
+                match check_punct(iter, ';') {
+                    Ok(_) | Err(SolveError::End) => {}
+                    Err(error) => return Err(error),
+                }
                 Some(output)
             }
-            ';' => None,
-            _ => return Err(SolveError::Syntax(TokenTree::Punct(punct))),
+            Ok(punct) if punct.as_char() == ';' => None,
+            Ok(punct) => {
+                return Err(SolveError::Syntax(
+                    TokenTree::Punct(punct),
+                    "`,` or `;`".to_string(),
+                ))
+            }
+            Err(SolveError::End) => None,
+            Err(error) => return Err(error),
         };
 
         // return a statement
@@ -65,31 +131,70 @@ impl ParseTokens for Statement {
             input2,
             output1,
             output2,
+            target,
         })
     }
 }
 
 impl Statement {
+    /// Reuse the input expressions already evaluated by the previous statements
+    pub(crate) fn share_inputs(&mut self, evaluated: &mut EvaluatedInputs, index: usize) {
+        evaluated.share(&mut self.input1, index);
+        evaluated.share(&mut self.input2, index);
+
+        // the outputs shadow variables the following expressions may refer to
+        evaluated.invalidate(self.output1.get_ident(index).as_ref());
+        if let Some(output) = &self.output2 {
+            evaluated.invalidate(output.get_ident(index).as_ref());
+        }
+
+        // the assignments modify the target, the expressions referring to it change value
+        if let Some(target) = &self.target {
+            evaluated.invalidate_all(target);
+        }
+    }
+
     /// Convert the statement to a token stream
     pub(crate) fn to_tokens(
         &self,
         float_type: &FloatType,
         index: usize,
     ) -> Result<TokenStream, SolveError> {
-        // evaluate the first output result
-        let out1 = select_function(float_type, index, &self.input1, &self.input2, &self.output1)?;
-
-        // evaluate the second output result
-        let out2 = if let Some(output) = &self.output2 {
-            select_function(float_type, index, &self.input1, &self.input2, output)?
-        } else {
-            TokenStream::new()
-        };
+        if float_type.is_const() {
+            if let Some(target) = &self.target {
+                return Err(SolveError::ConstAssignment(target.clone()));
+            }
+        }
 
         // pre-evaluate the input variables (if necessary)
-        let in1 = self.input1.pre_evaluate(float_type, index);
-        let in2 = self.input2.pre_evaluate(float_type, index);
+        let mut tokens = self.input1.pre_evaluate(float_type, index);
+        tokens.extend(self.input2.pre_evaluate(float_type, index));
+
+        // evaluate the target to assign to only once
+        let target = self.target.as_ref().map(|target| {
+            let name = Ident::new(&format!["__target{}", index], Span::mixed_site());
+            tokens.extend(quote![ let #name = &mut (#target); ]);
+            name
+        });
+
+        // evaluate the outputs, either into variables or into the fields of the same name
+        let outputs = [Some(&self.output1), self.output2.as_ref()];
+        for output in outputs.into_iter().flatten() {
+            if let Some(target) = &target {
+                let field = output.get_ident(index);
+                let call = function_call(float_type, index, &self.input1, &self.input2, output)?;
+                tokens.extend(quote![ #target.#field = #call; ]);
+            } else {
+                tokens.extend(select_function(
+                    float_type,
+                    index,
+                    &self.input1,
+                    &self.input2,
+                    output,
+                )?);
+            }
+        }
 
-        Ok(quote![ #in1 #in2 #out1 #out2 ])
+        Ok(tokens)
     }
 }
@@ -6,5 +6,15 @@ use proc_macro::TokenStream;
 /// Compute jump parameters
 #[proc_macro]
 pub fn jump_parameters(input: TokenStream) -> TokenStream {
-    jump::generate_calculator(input.into()).unwrap().into()
+    jump::generate_calculator(input.into())
+        .unwrap_or_else(|error| syn::Error::from(error).into_compile_error())
+        .into()
+}
+
+/// Compute all jump parameters from two of them
+#[proc_macro]
+pub fn all_jump_parameters(input: TokenStream) -> TokenStream {
+    jump::generate_all(input.into())
+        .unwrap_or_else(|error| syn::Error::from(error).into_compile_error())
+        .into()
 }
@@ -0,0 +1,6 @@
+/// Check the diagnostics emitted on invalid macro inputs
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
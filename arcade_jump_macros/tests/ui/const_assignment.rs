@@ -0,0 +1,15 @@
+use arcade_jump_macros::jump_parameters;
+
+struct Character {
+    impulse: f32,
+}
+
+fn main() {
+    let mut character = Character { impulse: 0.0 };
+
+    jump_parameters![
+        use const f32;
+        into character;
+        20.0: Height, 10.0: Time => impulse: Impulse;
+    ];
+}
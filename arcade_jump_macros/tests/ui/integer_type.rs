@@ -0,0 +1,11 @@
+use arcade_jump_macros::jump_parameters;
+
+fn main() {
+    let my_height: i32 = 20;
+    let my_gravity: i32 = -1;
+
+    jump_parameters![
+        use i32;
+        my_height: Height, my_gravity: Gravity => my_time: Time;
+    ];
+}
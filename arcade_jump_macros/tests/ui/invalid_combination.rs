@@ -0,0 +1,11 @@
+use arcade_jump_macros::jump_parameters;
+
+fn main() {
+    let my_height: f32 = 20.0;
+    let other_height: f32 = 10.0;
+
+    jump_parameters![
+        use f32;
+        my_height: Height, other_height: Height => my_impulse: Impulse;
+    ];
+}
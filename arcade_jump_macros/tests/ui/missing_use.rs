@@ -0,0 +1,10 @@
+use arcade_jump_macros::jump_parameters;
+
+fn main() {
+    let my_height: f32 = 20.0;
+    let my_time: f32 = 0.5;
+
+    jump_parameters![
+        my_height: Height, my_time: Time => my_impulse: Impulse;
+    ];
+}
@@ -0,0 +1,11 @@
+use arcade_jump_macros::jump_parameters;
+
+fn main() {
+    let my_height: f32 = 20.0;
+    let my_time: f32 = 0.5;
+
+    jump_parameters![
+        use f32;
+        my_height: Height, my_time: Tiem => my_impulse: Impulse;
+    ];
+}